@@ -6,7 +6,6 @@ use std::u128::U128;
 const DEFAULT_TOKEN_DECIMALS: u8 = 9u8;
 
 /// Gas data for a remote domain.
-/// TODO: consider packing data to reduce storage costs.
 pub struct RemoteGasData {
     token_exchange_rate: U128,
     gas_price: U128,
@@ -23,13 +22,64 @@ impl RemoteGasData {
     }
 }
 
+/// The max value of the upper word of a packed token exchange rate.
+/// Exchange rates are packed into 120 bits, so the upper word has 56 bits.
+const MAX_PACKED_EXCHANGE_RATE_UPPER: u64 = 0x00ffffffffffffff;
+
+/// Remote gas data packed into 4 words, which fit in a single storage slot.
+/// The layout is:
+/// * `word_0` - token decimals (8 bits) | token exchange rate upper (56 bits)
+/// * `word_1` - token exchange rate lower (64 bits)
+/// * `word_2` - gas price upper (64 bits)
+/// * `word_3` - gas price lower (64 bits)
+pub struct PackedRemoteGasData {
+    word_0: u64,
+    word_1: u64,
+    word_2: u64,
+    word_3: u64,
+}
+
+impl RemoteGasData {
+    /// Packs the gas data into a single storage slot.
+    /// Reverts if the token exchange rate exceeds 120 bits.
+    pub fn pack(self) -> PackedRemoteGasData {
+        require(self.token_exchange_rate.upper <= MAX_PACKED_EXCHANGE_RATE_UPPER, "exchange rate too large");
+
+        let token_decimals: u64 = self.token_decimals;
+        PackedRemoteGasData {
+            word_0: (token_decimals << 56) | self.token_exchange_rate.upper,
+            word_1: self.token_exchange_rate.lower,
+            word_2: self.gas_price.upper,
+            word_3: self.gas_price.lower,
+        }
+    }
+}
+
+impl PackedRemoteGasData {
+    /// Unpacks the gas data.
+    pub fn unpack(self) -> RemoteGasData {
+        RemoteGasData {
+            token_exchange_rate: U128::from((self.word_0 & MAX_PACKED_EXCHANGE_RATE_UPPER, self.word_1)),
+            gas_price: U128::from((self.word_2, self.word_3)),
+            token_decimals: self.word_0 >> 56,
+        }
+    }
+}
+
 /// An oracle that provides gas data for a remote domain.
 abi GasOracle {
     #[storage(read)]
     fn get_remote_gas_data(domain: u32) -> RemoteGasData;
 }
 
-/// Logged when a gas payment is made.
+/// A gas oracle that reports missing or stale gas data as None rather than
+/// reverting, allowing callers to tolerate failing oracles.
+abi FallibleGasOracle {
+    #[storage(read)]
+    fn try_get_remote_gas_data(domain: u32) -> Option<RemoteGasData>;
+}
+
+/// Logged when a gas payment is made. The `payment` is denominated in the base asset.
 pub struct GasPaymentEvent {
     message_id: b256,
     gas_amount: u64,
@@ -45,3 +95,36 @@ abi InterchainGasPaymaster {
     #[storage(read)]
     fn quote_gas_payment(destination_domain: u32, gas_amount: u64) -> u64;
 }
+
+#[test()]
+fn test_pack_and_unpack_remote_gas_data() {
+    let gas_data = RemoteGasData {
+        token_exchange_rate: U128::from((MAX_PACKED_EXCHANGE_RATE_UPPER, 0xffffffffffffffff)),
+        gas_price: U128::from((0xffffffffffffffff, 0xffffffffffffffff)),
+        token_decimals: 255u8,
+    };
+    let unpacked = gas_data.pack().unpack();
+    assert(unpacked.token_exchange_rate == gas_data.token_exchange_rate);
+    assert(unpacked.gas_price == gas_data.gas_price);
+    assert(unpacked.token_decimals == gas_data.token_decimals);
+
+    let gas_data = RemoteGasData {
+        token_exchange_rate: U128::from((1, 2)),
+        gas_price: U128::from((3, 4)),
+        token_decimals: 18u8,
+    };
+    let unpacked = gas_data.pack().unpack();
+    assert(unpacked.token_exchange_rate == gas_data.token_exchange_rate);
+    assert(unpacked.gas_price == gas_data.gas_price);
+    assert(unpacked.token_decimals == gas_data.token_decimals);
+}
+
+#[test(should_revert)]
+fn test_pack_reverts_if_exchange_rate_too_large() {
+    let gas_data = RemoteGasData {
+        token_exchange_rate: U128::from((MAX_PACKED_EXCHANGE_RATE_UPPER + 1, 0)),
+        gas_price: U128::new(),
+        token_decimals: 9u8,
+    };
+    let _ = gas_data.pack();
+}
@@ -2,6 +2,7 @@ library;
 
 mod igp;
 mod ownable;
+mod validator_key;
 
 use std::bytes::Bytes;
 
@@ -14,7 +15,7 @@ pub struct DispatchIdEvent {
     message_id: b256,
 }
 
-/// Logged when a message is processed.
+/// Logged when a message is processed and handled by its recipient.
 pub struct ProcessEvent {
     message_id: b256,
     origin: u32,
@@ -22,11 +23,57 @@ pub struct ProcessEvent {
     recipient: b256,
 }
 
+/// Logged when the recipient's handling of a processed message is deferred.
+pub struct HandleDeferredEvent {
+    message_id: b256,
+}
+
+/// Logged when a deferred message is handled by its recipient.
+pub struct DeferredHandleProcessedEvent {
+    message_id: b256,
+}
+
+/// The result of processing a message.
+pub enum ProcessResult {
+    /// The message was verified and handled by its recipient.
+    Processed: (),
+    /// The message's version does not match the Mailbox's version.
+    InvalidVersion: (),
+    /// The message's destination is not the local domain.
+    InvalidDestination: (),
+    /// Processing messages from the message's origin is paused.
+    OriginPaused: (),
+    /// The message has already been delivered.
+    AlreadyDelivered: (),
+    /// The message was rejected by its ISM.
+    VerificationFailed: (),
+}
+
 /// Logged when the default ISM is set.
 pub struct DefaultIsmSetEvent {
     module: ContractId,
 }
 
+/// Logged when inbound messages from an origin domain are paused.
+pub struct OriginPausedEvent {
+    origin: u32,
+}
+
+/// Logged when inbound messages from an origin domain are unpaused.
+pub struct OriginUnpausedEvent {
+    origin: u32,
+}
+
+/// Logged when outbound messages to a destination domain are paused.
+pub struct DestinationPausedEvent {
+    destination: u32,
+}
+
+/// Logged when outbound messages to a destination domain are unpaused.
+pub struct DestinationUnpausedEvent {
+    destination: u32,
+}
+
 abi Mailbox {
     /// Dispatches a message to the destination domain and recipient.
     /// Returns the message's ID.
@@ -48,6 +95,59 @@ abi Mailbox {
     #[storage(read, write)]
     fn process(metadata: Bytes, message: Bytes);
 
+    /// Processes multiple messages, returning the result of processing each.
+    ///
+    /// ### Arguments
+    ///
+    /// * `metadatas` - The metadata for ISM verification of each message.
+    /// * `messages` - The messages as emitted by dispatch.
+    /// * `revert_on_failure` - Whether to revert the entire batch if any
+    ///   message fails to be processed, as opposed to skipping it.
+    #[storage(read, write)]
+    fn process_batch(metadatas: Vec<Bytes>, messages: Vec<Bytes>, revert_on_failure: bool) -> Vec<ProcessResult>;
+
+    /// Processes a message, forwarding at most `handle_gas_limit` gas to the
+    /// recipient's `handle` function. If the recipient runs out of gas or
+    /// reverts, the entire call reverts.
+    ///
+    /// ### Arguments
+    ///
+    /// * `metadata` - The metadata for ISM verification.
+    /// * `message` - The message as emitted by dispatch.
+    /// * `handle_gas_limit` - The max gas forwarded to the recipient.
+    #[storage(read, write)]
+    fn process_with_gas_limit(metadata: Bytes, message: Bytes, handle_gas_limit: u64);
+
+    /// Verifies and delivers a message without calling the recipient's
+    /// `handle` function. The message is recorded as pending, and may be
+    /// handled by anyone using `retry_handle`.
+    ///
+    /// ### Arguments
+    ///
+    /// * `metadata` - The metadata for ISM verification.
+    /// * `message` - The message as emitted by dispatch.
+    #[storage(read, write)]
+    fn process_and_defer_handle(metadata: Bytes, message: Bytes);
+
+    /// Calls the recipient's `handle` function for a pending message.
+    /// If `handle` reverts, the entire call reverts, so the message remains
+    /// pending and may be retried.
+    ///
+    /// ### Arguments
+    ///
+    /// * `message` - The message as emitted by dispatch.
+    #[storage(read, write)]
+    fn retry_handle(message: Bytes);
+
+    /// Returns true if the message has been delivered but not yet
+    /// handled by its recipient.
+    ///
+    /// ### Arguments
+    ///
+    /// * `message_id` - The unique identifier of the message.
+    #[storage(read)]
+    fn is_handle_pending(message_id: b256) -> bool;
+
     /// Returns true if the message has been processed.
     ///
     /// ### Arguments
@@ -82,6 +182,58 @@ abi Mailbox {
     fn latest_checkpoint() -> (b256, u32);
 }
 
+/// Allows traffic with individual domains to be paused, as opposed to
+/// pausing the entire Mailbox.
+abi DomainPausable {
+    /// Returns true if processing messages from the origin domain is paused.
+    ///
+    /// ### Arguments
+    ///
+    /// * `origin` - The origin domain identifier.
+    #[storage(read)]
+    fn is_origin_paused(origin: u32) -> bool;
+
+    /// Pauses processing messages from the origin domain.
+    ///
+    /// ### Arguments
+    ///
+    /// * `origin` - The origin domain identifier.
+    #[storage(read, write)]
+    fn pause_origin(origin: u32);
+
+    /// Unpauses processing messages from the origin domain.
+    ///
+    /// ### Arguments
+    ///
+    /// * `origin` - The origin domain identifier.
+    #[storage(read, write)]
+    fn unpause_origin(origin: u32);
+
+    /// Returns true if dispatching messages to the destination domain is paused.
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination` - The destination domain identifier.
+    #[storage(read)]
+    fn is_destination_paused(destination: u32) -> bool;
+
+    /// Pauses dispatching messages to the destination domain.
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination` - The destination domain identifier.
+    #[storage(read, write)]
+    fn pause_destination(destination: u32);
+
+    /// Unpauses dispatching messages to the destination domain.
+    ///
+    /// ### Arguments
+    ///
+    /// * `destination` - The destination domain identifier.
+    #[storage(read, write)]
+    fn unpause_destination(destination: u32);
+}
+
 pub enum ModuleType {
     UNUSED_0: (),
     ROUTING: (),
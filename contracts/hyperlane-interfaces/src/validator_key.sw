@@ -0,0 +1,98 @@
+library;
+
+use std::{
+    b512::B512,
+    constants::ZERO_B256,
+    ecr::{
+        ec_recover,
+        EcRecoverError,
+    },
+    hash::{
+        keccak256,
+        sha256,
+    },
+    vm::evm::evm_address::EvmAddress,
+};
+
+/// A validator's identity, tagged by the type of secp256k1 key it signs with.
+pub enum ValidatorKey {
+    /// An Ethereum-style address, i.e. the last 20 bytes of the
+    /// keccak256 hash of the public key.
+    Evm: EvmAddress,
+    /// A native Fuel address, i.e. the sha256 hash of the public key.
+    Fuel: Address,
+}
+
+impl core::ops::Eq for ValidatorKey {
+    fn eq(self, other: Self) -> bool {
+        match self {
+            ValidatorKey::Evm(a) => match other {
+                ValidatorKey::Evm(b) => a == b,
+                _ => false,
+            },
+            ValidatorKey::Fuel(a) => match other {
+                ValidatorKey::Fuel(b) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl ValidatorKey {
+    /// Returns true if the key is the zero address.
+    pub fn is_zero(self) -> bool {
+        match self {
+            ValidatorKey::Evm(address) => address == EvmAddress::from(ZERO_B256),
+            ValidatorKey::Fuel(address) => address == Address::from(ZERO_B256),
+        }
+    }
+}
+
+/// The signer of a digest, which may be compared against validator keys of any type.
+/// A signature is valid for every type of key, so the digest must commit to
+/// the key type it's checked against to prevent replays across key types.
+pub struct Signer {
+    evm: EvmAddress,
+    fuel: Address,
+}
+
+impl Signer {
+    /// Recovers the signer of `digest` from `signature`.
+    /// Equivalent to both `ec_recover_evm_address` and `ec_recover_address`,
+    /// but only recovers the public key once.
+    pub fn recover(signature: B512, digest: b256) -> Result<Self, EcRecoverError> {
+        let public_key = ec_recover(signature, digest)?;
+        let public_key = ((public_key.bytes)[0], (public_key.bytes)[1]);
+        Result::Ok(Self {
+            evm: EvmAddress::from(keccak256(public_key)),
+            fuel: Address::from(sha256(public_key)),
+        })
+    }
+
+    /// Returns true if `key` is the signer's key.
+    pub fn is(self, key: ValidatorKey) -> bool {
+        match key {
+            ValidatorKey::Evm(address) => address == self.evm,
+            ValidatorKey::Fuel(address) => address == self.fuel,
+        }
+    }
+}
+
+#[test()]
+fn test_validator_key_eq() {
+    let value = 0x000000000000000000000000cafecafecafecafecafecafecafecafecafecafe;
+    let evm = ValidatorKey::Evm(EvmAddress::from(value));
+    let fuel = ValidatorKey::Fuel(Address::from(value));
+
+    assert(evm == ValidatorKey::Evm(EvmAddress::from(value)));
+    assert(fuel == ValidatorKey::Fuel(Address::from(value)));
+    // Keys with the same value but different types are not equal.
+    assert(evm != fuel);
+}
+
+#[test()]
+fn test_validator_key_is_zero() {
+    assert(ValidatorKey::Evm(EvmAddress::from(ZERO_B256)).is_zero());
+    assert(ValidatorKey::Fuel(Address::from(ZERO_B256)).is_zero());
+    assert(!ValidatorKey::Fuel(Address::from(0x0000000000000000000000000000000000000000000000000000000000000001)).is_zero());
+}
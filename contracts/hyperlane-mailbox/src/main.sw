@@ -6,17 +6,35 @@ use std_lib_extended::bytes::*;
 
 use merkle::StorageMerkleTree;
 use ownership::{data_structures::State, only_owner, owner, set_ownership, transfer_ownership};
-use pause::{interface::Pausable, is_paused, pause, require_unpaused, unpause};
+use pause::{
+    interface::Pausable,
+    is_paused,
+    only_pauser_or_owner,
+    pause,
+    pauser,
+    remove_pauser,
+    require_unpaused,
+    set_pauser,
+    unpause,
+};
 use reentrancy::reentrancy_guard;
 
 use hyperlane_interfaces::{
     DefaultIsmSetEvent,
+    DeferredHandleProcessedEvent,
+    DestinationPausedEvent,
+    DestinationUnpausedEvent,
     DispatchIdEvent,
+    DomainPausable,
+    HandleDeferredEvent,
     InterchainSecurityModule,
     Mailbox,
     MessageRecipient,
+    OriginPausedEvent,
+    OriginUnpausedEvent,
     ownable::Ownable,
     ProcessEvent,
+    ProcessResult,
 };
 use hyperlane_message::{EncodedMessage, Message};
 
@@ -37,11 +55,20 @@ configurable {
     LOCAL_DOMAIN: u32 = 0x6675656cu32,
 }
 
+/// The Mailbox may be deployed behind the `proxy` contract, in which case upgrades
+/// must preserve this storage layout. New fields may only be appended, and existing
+/// fields must not be reordered, removed, or have their types changed.
 storage {
     /// A merkle tree that includes outbound message IDs as leaves.
     merkle_tree: StorageMerkleTree = StorageMerkleTree {},
     delivered: StorageMap<b256, bool> = StorageMap {},
     default_ism: ContractId = ZERO_ID,
+    /// Origin domains that inbound messages are not processed from.
+    paused_origins: StorageMap<u32, bool> = StorageMap {},
+    /// Destination domains that outbound messages are not dispatched to.
+    paused_destinations: StorageMap<u32, bool> = StorageMap {},
+    /// Delivered messages that have not yet been handled by their recipient.
+    pending_handles: StorageMap<b256, bool> = StorageMap {},
 }
 
 impl Mailbox for Contract {
@@ -61,6 +88,8 @@ impl Mailbox for Contract {
     ) -> b256 {
         // Prevent messages from being dispatched when the mailbox is paused.
         require_unpaused();
+        // Prevent messages from being dispatched to a paused destination.
+        require(!is_destination_paused(destination_domain), "destination is paused");
 
         require(message_body.len() <= MAX_MESSAGE_BODY_BYTES, "msg too long");
 
@@ -103,39 +132,123 @@ impl Mailbox for Contract {
         // Prevent messages from being processed when the mailbox is paused.
         require_unpaused();
 
+        let _ = process_message(metadata, _message, true, Option::None);
+    }
+
+    /// Processes a message, forwarding at most `handle_gas_limit` gas to the
+    /// recipient's `handle` function. This only caps the gas a relayer spends
+    /// on a recipient, and doesn't isolate the relayer from a failing one:
+    /// if the recipient runs out of gas or reverts, the entire call reverts
+    /// and the message isn't delivered. Use `process_and_defer_handle` to
+    /// deliver a message regardless of whether its recipient can handle it.
+    #[storage(read, write)]
+    fn process_with_gas_limit(
+        metadata: Bytes,
+        _message: Bytes,
+        handle_gas_limit: u64,
+    ) {
+        // Prevent reentrancy.
+        reentrancy_guard();
+        // Prevent messages from being processed when the mailbox is paused.
+        require_unpaused();
+
+        let _ = process_message(
+            metadata,
+            _message,
+            true,
+            Option::Some(handle_gas_limit),
+        );
+    }
+
+    /// Verifies and delivers a message without calling the recipient's
+    /// `handle` function, isolating the relayer from a failing recipient.
+    /// The message is recorded as pending until handled via `retry_handle`,
+    /// and its `ProcessEvent` is only logged once it has been handled.
+    #[storage(read, write)]
+    fn process_and_defer_handle(metadata: Bytes, _message: Bytes) {
+        // Prevent reentrancy.
+        reentrancy_guard();
+        // Prevent messages from being processed when the mailbox is paused.
+        require_unpaused();
+
         let message = EncodedMessage {
             bytes: _message,
         };
-
-        require(message.version() == VERSION, "!version");
-        require(message.destination() == LOCAL_DOMAIN, "!destination");
+        let _ = deliver_message(metadata, message, true);
 
         let id = message.id();
-        require(!delivered(id), "delivered");
-        storage.delivered.insert(id, true);
+        storage.pending_handles.insert(id, true);
+        log(HandleDeferredEvent { message_id: id });
+    }
 
-        let recipient = message.recipient();
+    /// Calls the recipient's `handle` function for a pending message.
+    /// Callable by anyone. If `handle` reverts, the entire call reverts,
+    /// so the message remains pending and may be retried.
+    #[storage(read, write)]
+    fn retry_handle(_message: Bytes) {
+        // Prevent reentrancy.
+        reentrancy_guard();
+        // Prevent messages from being handled when the mailbox is paused.
+        require_unpaused();
 
-        let msg_recipient = abi(MessageRecipient, recipient);
-        let mut ism_id = msg_recipient.interchain_security_module();
-        if (ism_id == ZERO_ID) {
-            ism_id = storage.default_ism;
-        }
+        let message = EncodedMessage {
+            bytes: _message,
+        };
+        // Prevent messages from a paused origin from being handled.
+        require(!is_origin_paused(message.origin()), "origin is paused");
 
-        let ism = abi(InterchainSecurityModule, ism_id.into());
-        require(ism.verify(metadata, _message), "!module");
+        let id = message.id();
+        require(is_handle_pending(id), "!pending");
+        storage.pending_handles.insert(id, false);
 
-        let origin = message.origin();
-        let sender = message.sender();
+        let msg_recipient = abi(MessageRecipient, message.recipient());
+        msg_recipient.handle(message.origin(), message.sender(), message.body());
 
-        msg_recipient.handle(origin, sender, message.body());
+        log_process_event(message);
+        log(DeferredHandleProcessedEvent { message_id: id });
+    }
 
-        log(ProcessEvent {
-            message_id: id,
-            origin,
-            sender,
-            recipient,
-        });
+    /// Returns true if the message has been delivered but not yet
+    /// handled by its recipient.
+    #[storage(read)]
+    fn is_handle_pending(message_id: b256) -> bool {
+        is_handle_pending(message_id)
+    }
+
+    /// Processes multiple messages sequentially, returning the result of
+    /// processing each message.
+    ///
+    /// If `revert_on_failure` is true, the entire batch reverts if any message
+    /// fails to be processed. Otherwise, messages that fail the Mailbox's
+    /// checks or are rejected by their ISM are skipped and their failure is
+    /// reported in the results. Note that reverts originating from an ISM or
+    /// recipient always revert the entire batch.
+    #[storage(read, write)]
+    fn process_batch(
+        metadatas: Vec<Bytes>,
+        messages: Vec<Bytes>,
+        revert_on_failure: bool,
+    ) -> Vec<ProcessResult> {
+        // Prevent reentrancy.
+        reentrancy_guard();
+        // Prevent messages from being processed when the mailbox is paused.
+        require_unpaused();
+
+        let count = messages.len();
+        require(count == metadatas.len(), "!length");
+
+        let mut results = Vec::with_capacity(count);
+        let mut i = 0;
+        while i < count {
+            results.push(process_message(
+                metadatas.get(i).unwrap(),
+                messages.get(i).unwrap(),
+                revert_on_failure,
+                Option::None,
+            ));
+            i += 1;
+        }
+        results
     }
 
     /// Returns the number of inserted leaves (i.e. messages) in the merkle tree.
@@ -190,10 +303,10 @@ impl Pausable for Contract {
     }
 
     /// Pauses the contract if it is not already paused.
-    /// Reverts if the msg_sender is not the current owner.
+    /// Reverts if the msg_sender is not the pauser or the current owner.
     #[storage(read, write)]
     fn pause() {
-        only_owner();
+        only_pauser_or_owner();
         pause()
     }
 
@@ -204,6 +317,214 @@ impl Pausable for Contract {
         only_owner();
         unpause()
     }
+
+    /// Gets the pauser, if one has been set.
+    #[storage(read)]
+    fn pauser() -> Option<Identity> {
+        pauser()
+    }
+
+    /// Sets the pauser, who may pause but not unpause.
+    /// Reverts if the msg_sender is not the current owner.
+    #[storage(read, write)]
+    fn set_pauser(pauser: Identity) {
+        only_owner();
+        set_pauser(pauser)
+    }
+
+    /// Removes the pauser, so that only the owner may pause.
+    /// Reverts if the msg_sender is not the current owner, or if no pauser is set.
+    #[storage(read, write)]
+    fn remove_pauser() {
+        only_owner();
+        remove_pauser()
+    }
+}
+
+impl DomainPausable for Contract {
+    /// Returns true if processing messages from the origin domain is paused.
+    #[storage(read)]
+    fn is_origin_paused(origin: u32) -> bool {
+        is_origin_paused(origin)
+    }
+
+    /// Pauses processing messages from the origin domain.
+    /// Reverts if the msg_sender is not the pauser or the current owner,
+    /// or if the origin is already paused.
+    #[storage(read, write)]
+    fn pause_origin(origin: u32) {
+        only_pauser_or_owner();
+        require(!is_origin_paused(origin), "origin is already paused");
+
+        storage.paused_origins.insert(origin, true);
+        log(OriginPausedEvent { origin });
+    }
+
+    /// Unpauses processing messages from the origin domain.
+    /// Reverts if the msg_sender is not the current owner or if the
+    /// origin is not paused.
+    #[storage(read, write)]
+    fn unpause_origin(origin: u32) {
+        only_owner();
+        require(is_origin_paused(origin), "origin is not paused");
+
+        storage.paused_origins.insert(origin, false);
+        log(OriginUnpausedEvent { origin });
+    }
+
+    /// Returns true if dispatching messages to the destination domain is paused.
+    #[storage(read)]
+    fn is_destination_paused(destination: u32) -> bool {
+        is_destination_paused(destination)
+    }
+
+    /// Pauses dispatching messages to the destination domain.
+    /// Reverts if the msg_sender is not the pauser or the current owner,
+    /// or if the destination is already paused.
+    #[storage(read, write)]
+    fn pause_destination(destination: u32) {
+        only_pauser_or_owner();
+        require(!is_destination_paused(destination), "destination is already paused");
+
+        storage.paused_destinations.insert(destination, true);
+        log(DestinationPausedEvent { destination });
+    }
+
+    /// Unpauses dispatching messages to the destination domain.
+    /// Reverts if the msg_sender is not the current owner or if the
+    /// destination is not paused.
+    #[storage(read, write)]
+    fn unpause_destination(destination: u32) {
+        only_owner();
+        require(is_destination_paused(destination), "destination is not paused");
+
+        storage.paused_destinations.insert(destination, false);
+        log(DestinationUnpausedEvent { destination });
+    }
+}
+
+/// Processes a single message. Access control, pausing, and reentrancy
+/// checks are expected to have already been performed by the caller.
+///
+/// If `revert_on_failure` is true, reverts if the message cannot be processed.
+/// Otherwise, the reason the message could not be processed is returned and
+/// no state is changed.
+///
+/// If `handle_gas_limit` is Some, at most that much gas is forwarded to the
+/// recipient's `handle` function.
+#[storage(read, write)]
+fn process_message(
+    metadata: Bytes,
+    message_bytes: Bytes,
+    revert_on_failure: bool,
+    handle_gas_limit: Option<u64>,
+) -> ProcessResult {
+    let message = EncodedMessage {
+        bytes: message_bytes,
+    };
+
+    let result = deliver_message(metadata, message, revert_on_failure);
+    match result {
+        ProcessResult::Processed => {},
+        _ => return result,
+    }
+
+    let msg_recipient = abi(MessageRecipient, message.recipient());
+    match handle_gas_limit {
+        Option::Some(gas) => msg_recipient.handle {
+            gas: gas,
+        }(message.origin(), message.sender(), message.body()),
+        Option::None => msg_recipient.handle(message.origin(), message.sender(), message.body()),
+    }
+
+    log_process_event(message);
+    ProcessResult::Processed
+}
+
+/// Verifies a message with its ISM and marks it as delivered, without
+/// calling the recipient's `handle` function.
+///
+/// If `revert_on_failure` is true, reverts if the message cannot be delivered.
+/// Otherwise, the reason the message could not be delivered is returned and
+/// no state is changed.
+#[storage(read, write)]
+fn deliver_message(
+    metadata: Bytes,
+    message: EncodedMessage,
+    revert_on_failure: bool,
+) -> ProcessResult {
+    let result = precheck_message(message);
+    if revert_on_failure {
+        require_processed(result);
+    }
+    match result {
+        ProcessResult::Processed => {},
+        _ => return result,
+    }
+
+    let id = message.id();
+    storage.delivered.insert(id, true);
+
+    let recipient = message.recipient();
+
+    let msg_recipient = abi(MessageRecipient, recipient);
+    let mut ism_id = msg_recipient.interchain_security_module();
+    if (ism_id == ZERO_ID) {
+        ism_id = storage.default_ism;
+    }
+
+    let ism = abi(InterchainSecurityModule, ism_id.into());
+    if !ism.verify(metadata, message.bytes) {
+        require(!revert_on_failure, "!module");
+        // Undo marking the message as delivered so it can be processed later.
+        storage.delivered.insert(id, false);
+        return ProcessResult::VerificationFailed;
+    }
+
+    ProcessResult::Processed
+}
+
+/// Logs a `ProcessEvent` for a message that has been handled by its recipient.
+fn log_process_event(message: EncodedMessage) {
+    log(ProcessEvent {
+        message_id: message.id(),
+        origin: message.origin(),
+        sender: message.sender(),
+        recipient: message.recipient(),
+    });
+}
+
+/// Performs the checks on a message that don't require any external calls.
+/// Returns `ProcessResult::Processed` if all checks pass, or the reason
+/// the message cannot be processed otherwise.
+#[storage(read)]
+fn precheck_message(message: EncodedMessage) -> ProcessResult {
+    if message.version() != VERSION {
+        return ProcessResult::InvalidVersion;
+    }
+    if message.destination() != LOCAL_DOMAIN {
+        return ProcessResult::InvalidDestination;
+    }
+    // Prevent messages from a paused origin from being processed.
+    if is_origin_paused(message.origin()) {
+        return ProcessResult::OriginPaused;
+    }
+    if delivered(message.id()) {
+        return ProcessResult::AlreadyDelivered;
+    }
+    ProcessResult::Processed
+}
+
+/// Reverts with a reason if `result` is not `ProcessResult::Processed`.
+fn require_processed(result: ProcessResult) {
+    match result {
+        ProcessResult::Processed => {},
+        ProcessResult::InvalidVersion => require(false, "!version"),
+        ProcessResult::InvalidDestination => require(false, "!destination"),
+        ProcessResult::OriginPaused => require(false, "origin is paused"),
+        ProcessResult::AlreadyDelivered => require(false, "delivered"),
+        ProcessResult::VerificationFailed => require(false, "!module"),
+    }
 }
 
 /// Returns the number of inserted leaves (i.e. messages) in the merkle tree.
@@ -220,11 +541,26 @@ fn root() -> b256 {
     storage.merkle_tree.root()
 }
 
+#[storage(read)]
+fn is_handle_pending(message_id: b256) -> bool {
+    storage.pending_handles.get(message_id).unwrap_or(false)
+}
+
 #[storage(read)]
 fn delivered(message_id: b256) -> bool {
     storage.delivered.get(message_id).unwrap_or(false)
 }
 
+#[storage(read)]
+fn is_origin_paused(origin: u32) -> bool {
+    storage.paused_origins.get(origin).unwrap_or(false)
+}
+
+#[storage(read)]
+fn is_destination_paused(destination: u32) -> bool {
+    storage.paused_destinations.get(destination).unwrap_or(false)
+}
+
 /// Gets the b256 representation of the msg_sender.
 fn msg_sender_b256() -> b256 {
     match msg_sender().unwrap() {
@@ -1,14 +1,14 @@
 use ethers::types::H256;
 use fuels::{
     prelude::*,
-    tx::{ContractId, Receipt},
+    tx::ContractId,
     types::{Bits256, Bytes, Identity},
 };
-use hyperlane_core::{Decode, Encode, HyperlaneMessage as HyperlaneAgentMessage};
+use hyperlane_core::{Encode, HyperlaneMessage as HyperlaneAgentMessage};
 use std::str::FromStr;
 use test_utils::{
-    bits256_to_h256, funded_wallet_with_private_key, get_revert_reason, get_revert_string,
-    h256_to_bits256,
+    bits256_to_h256, decode_revert_reason, dispatched_messages, funded_wallet_with_private_key,
+    h256_to_bits256, RevertReason,
 };
 
 mod mailbox_contract {
@@ -21,7 +21,11 @@ mod mailbox_contract {
     ));
 }
 
-use crate::mailbox_contract::{DefaultIsmSetEvent, DispatchIdEvent, Mailbox, ProcessEvent};
+use crate::mailbox_contract::{
+    DefaultIsmSetEvent, DeferredHandleProcessedEvent, DestinationPausedEvent,
+    DestinationUnpausedEvent, DispatchIdEvent, HandleDeferredEvent, Mailbox, OriginPausedEvent,
+    OriginUnpausedEvent, ProcessEvent, ProcessResult,
+};
 
 mod test_interchain_security_module_contract {
     use fuels::prelude::abigen;
@@ -40,6 +44,9 @@ abigen!(Contract(
 const NON_OWNER_PRIVATE_KEY: &str =
     "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
 
+const PAUSER_PRIVATE_KEY: &str =
+    "0x2ef987da35e5b389bb47cc4ec024ce0c37e5defd00de35fe61db6f50d1a858a1";
+
 // At the moment, the origin domain is hardcoded in the Mailbox contract.
 const TEST_LOCAL_DOMAIN: u32 = 0x6675656cu32;
 const TEST_REMOTE_DOMAIN: u32 = 0x112233cu32;
@@ -130,6 +137,22 @@ async fn get_contract_instance() -> (Mailbox<WalletUnlocked>, Bech32ContractId,
 
 // Gets the wallet address from the `Mailbox` instance, and
 // creates a test message with that address as the sender.
+/// Funds a pauser wallet and sets it as the Mailbox's pauser.
+async fn set_up_pauser(mailbox: &Mailbox<WalletUnlocked>) -> WalletUnlocked {
+    let pauser_wallet = funded_wallet_with_private_key(&mailbox.account(), PAUSER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    mailbox
+        .methods()
+        .set_pauser(Identity::Address(pauser_wallet.address().into()))
+        .call()
+        .await
+        .unwrap();
+
+    pauser_wallet
+}
+
 fn test_message(
     mailbox: &Mailbox<WalletUnlocked>,
     recipient: Bech32ContractId,
@@ -174,7 +197,10 @@ async fn test_dispatch_too_large_message() {
         .await
         .unwrap_err();
 
-    assert_eq!(get_revert_string(dispatch_err), "msg too long");
+    assert_eq!(
+        decode_revert_reason(dispatch_err).unwrap(),
+        RevertReason::require("msg too long")
+    );
 }
 
 #[tokio::test]
@@ -195,17 +221,10 @@ async fn test_dispatch_logs_message() {
         .await
         .unwrap();
 
-    // The log is expected to be the second receipt
-    let log_receipt = &dispatch_call.receipts[1];
-    let log_data = if let Receipt::LogData { data, .. } = log_receipt {
-        data
-    } else {
-        panic!("Expected LogData receipt. Receipt: {:?}", log_receipt);
-    };
-
-    let recovered_message = HyperlaneAgentMessage::read_from(&mut log_data.as_slice()).unwrap();
+    let recovered_messages = dispatched_messages(&dispatch_call.receipts).unwrap();
+    assert_eq!(recovered_messages.len(), 1);
     // Assert equality of the message ID
-    assert_eq!(recovered_message.id(), message_id);
+    assert_eq!(recovered_messages[0].id(), message_id);
 
     // Also make sure the DispatchIdEvent was logged
     let events = dispatch_call
@@ -280,7 +299,49 @@ async fn test_dispatch_reverts_if_paused() {
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.unwrap_err()), "contract is paused");
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("contract is paused")
+    );
+}
+
+#[tokio::test]
+async fn test_dispatch_reverts_if_destination_paused() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    mailbox
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    let call = mailbox
+        .methods()
+        .dispatch(
+            TEST_REMOTE_DOMAIN,
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            Bytes(vec![10u8; 100]),
+        )
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("destination is paused")
+    );
+
+    // Dispatching to other destinations is unaffected
+    mailbox
+        .methods()
+        .dispatch(
+            TEST_REMOTE_DOMAIN + 1,
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            Bytes(vec![10u8; 100]),
+        )
+        .call()
+        .await
+        .unwrap();
 }
 
 // ============ latest_checkpoint ============
@@ -295,8 +356,8 @@ async fn test_latest_checkpoint() {
     let call = mailbox.methods().latest_checkpoint().simulate().await;
     assert!(call.is_err());
     assert_eq!(
-        get_revert_string(call.err().unwrap()),
-        "no messages dispatched"
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("no messages dispatched")
     );
 
     mailbox
@@ -419,7 +480,10 @@ async fn test_process_deliver_twice() {
         .await
         .unwrap_err();
 
-    assert_eq!(get_revert_string(process_delivered_error), "delivered");
+    assert_eq!(
+        decode_revert_reason(process_delivered_error).unwrap(),
+        RevertReason::require("delivered")
+    );
 }
 
 #[tokio::test]
@@ -444,7 +508,10 @@ async fn test_process_module_reject() {
         .await
         .unwrap_err();
 
-    assert_eq!(get_revert_string(process_module_error), "!module");
+    assert_eq!(
+        decode_revert_reason(process_module_error).unwrap(),
+        RevertReason::require("!module")
+    );
 }
 
 #[tokio::test]
@@ -467,145 +534,1181 @@ async fn test_process_reverts_if_paused() {
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.unwrap_err()), "contract is paused");
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("contract is paused")
+    );
 }
 
-// ============ pause ============
+#[tokio::test]
+async fn test_process_reverts_if_origin_paused() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    mailbox
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    let metadata = vec![5u8; 100];
+
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    let call = mailbox
+        .methods()
+        .process(Bytes(metadata.clone()), Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("origin is paused")
+    );
+
+    // Once unpaused, the message can be processed
+    mailbox
+        .methods()
+        .unpause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    mailbox
+        .methods()
+        .process(Bytes(metadata), Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+}
+
+// ============ process_with_gas_limit ============
 
 #[tokio::test]
-async fn test_pause() {
-    let (mailbox, _, _) = get_contract_instance().await;
+async fn test_process_with_gas_limit() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
 
-    mailbox.methods().pause().call().await.unwrap();
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let contract_inputs = vec![ism_id.clone(), recipient_id.clone()];
 
-    let paused: bool = mailbox
+    mailbox
         .methods()
-        .is_paused()
-        .simulate()
+        .process_with_gas_limit(
+            Bytes(vec![5u8; 100]),
+            Bytes(agent_message.to_vec()),
+            500_000,
+        )
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
         .await
-        .unwrap()
-        .value;
+        .unwrap();
 
-    assert!(paused);
+    let msg_recipient = TestMessageRecipient::new(recipient_id, mailbox.account());
+    let handled = msg_recipient.methods().handled().simulate().await.unwrap();
+    assert!(handled.value);
 }
 
 #[tokio::test]
-async fn test_pause_reverts_if_not_owner() {
-    let (mailbox, _, _) = get_contract_instance().await;
+async fn test_process_with_gas_limit_reverts_if_handle_exceeds_limit() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
 
-    let non_owner_wallet =
-        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
-            .await
-            .unwrap();
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
 
     let call = mailbox
-        .with_account(non_owner_wallet)
-        .unwrap()
         .methods()
-        .pause()
+        .process_with_gas_limit(Bytes(vec![5u8; 100]), Bytes(agent_message.to_vec()), 1)
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
+
+    // The message was not delivered
+    let delivered = mailbox
+        .methods()
+        .delivered(h256_to_bits256(agent_message.id()))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(!delivered);
 }
 
-// ============ unpause ============
+// ============ process_and_defer_handle ============
 
 #[tokio::test]
-async fn test_unpause() {
-    let (mailbox, _, _) = get_contract_instance().await;
+async fn test_process_and_defer_handle() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
 
-    // First pause...
-    mailbox.methods().pause().call().await.unwrap();
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let message_id = h256_to_bits256(agent_message.id());
+    let contract_inputs = vec![ism_id.clone(), recipient_id.clone()];
 
-    // Now unpause!
-    mailbox.methods().unpause().call().await.unwrap();
+    // A failing recipient doesn't prevent the message from being delivered
+    let msg_recipient = TestMessageRecipient::new(recipient_id, mailbox.account());
+    msg_recipient
+        .methods()
+        .set_should_revert(true)
+        .call()
+        .await
+        .unwrap();
 
-    let paused: bool = mailbox
+    let call = mailbox
         .methods()
-        .is_paused()
+        .process_and_defer_handle(Bytes(vec![5u8; 100]), Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+
+    let events = call.get_logs_with_type::<HandleDeferredEvent>().unwrap();
+    assert_eq!(events, vec![HandleDeferredEvent { message_id }]);
+    // The ProcessEvent is only logged once the message is handled
+    let events = call.get_logs_with_type::<ProcessEvent>().unwrap();
+    assert_eq!(events, vec![]);
+
+    let delivered = mailbox
+        .methods()
+        .delivered(message_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(delivered);
+
+    let pending = mailbox
+        .methods()
+        .is_handle_pending(message_id)
         .simulate()
         .await
         .unwrap()
         .value;
+    assert!(pending);
 
-    assert!(!paused);
+    let handled = msg_recipient.methods().handled().simulate().await.unwrap();
+    assert!(!handled.value);
 }
 
+// ============ retry_handle ============
+
 #[tokio::test]
-async fn test_unpause_reverts_if_not_owner() {
-    let (mailbox, _, _) = get_contract_instance().await;
+async fn test_retry_handle() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
 
-    let non_owner_wallet =
-        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
-            .await
-            .unwrap();
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let message_id = h256_to_bits256(agent_message.id());
+    let contract_inputs = vec![ism_id.clone(), recipient_id.clone()];
 
-    // First pause...
-    mailbox.methods().pause().call().await.unwrap();
+    let msg_recipient = TestMessageRecipient::new(recipient_id, mailbox.account());
+    msg_recipient
+        .methods()
+        .set_should_revert(true)
+        .call()
+        .await
+        .unwrap();
+
+    mailbox
+        .methods()
+        .process_and_defer_handle(Bytes(vec![5u8; 100]), Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
 
+    // Retrying while the recipient reverts leaves the message pending
     let call = mailbox
-        .with_account(non_owner_wallet.clone())
-        .unwrap()
         .methods()
-        .unpause()
+        .retry_handle(Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
-}
-
-#[tokio::test]
-async fn test_set_default_ism() {
-    let (mailbox, ism_id, _) = get_contract_instance().await;
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("handle reverted")
+    );
 
-    // Sanity check the current default ISM is the one we expect
-    let default_ism = mailbox
+    let pending = mailbox
         .methods()
-        .get_default_ism()
+        .is_handle_pending(message_id)
         .simulate()
         .await
         .unwrap()
         .value;
-    assert_eq!(default_ism, ism_id.into());
+    assert!(pending);
 
-    let new_default_ism =
-        ContractId::from_str("0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe")
-            .unwrap();
-    assert_ne!(default_ism, new_default_ism);
+    // Once the recipient is fixed, the message can be handled
+    msg_recipient
+        .methods()
+        .set_should_revert(false)
+        .call()
+        .await
+        .unwrap();
 
     let call = mailbox
         .methods()
-        .set_default_ism(new_default_ism)
+        .retry_handle(Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
         .call()
         .await
         .unwrap();
-    // Ensure the event was logged
+
+    let events = call
+        .get_logs_with_type::<DeferredHandleProcessedEvent>()
+        .unwrap();
+    assert_eq!(events, vec![DeferredHandleProcessedEvent { message_id }]);
+    let events = call.get_logs_with_type::<ProcessEvent>().unwrap();
     assert_eq!(
-        call.get_logs_with_type::<DefaultIsmSetEvent>().unwrap(),
-        vec![DefaultIsmSetEvent {
-            module: new_default_ism,
-        }]
+        events,
+        vec![ProcessEvent {
+            message_id,
+            origin: agent_message.origin,
+            sender: h256_to_bits256(agent_message.sender),
+            recipient: h256_to_bits256(agent_message.recipient),
+        }],
     );
-    // And make sure the default ISM was really updated
-    let default_ism = mailbox
+
+    let pending = mailbox
         .methods()
-        .get_default_ism()
+        .is_handle_pending(message_id)
         .simulate()
         .await
         .unwrap()
         .value;
-    assert_eq!(default_ism, new_default_ism);
+    assert!(!pending);
+
+    let handled = msg_recipient.methods().handled().simulate().await.unwrap();
+    assert!(handled.value);
 }
 
 #[tokio::test]
-async fn test_set_default_ism_reverts_if_not_owner() {
-    let (mailbox, _, _) = get_contract_instance().await;
-    let new_default_ism =
-        ContractId::from_str("0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe")
-            .unwrap();
+async fn test_retry_handle_reverts_if_paused() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
 
-    let non_owner_wallet =
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    mailbox
+        .methods()
+        .process_and_defer_handle(Bytes(vec![5u8; 100]), Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+
+    mailbox.methods().pause().call().await.unwrap();
+
+    let call = mailbox
+        .methods()
+        .retry_handle(Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("contract is paused")
+    );
+}
+
+#[tokio::test]
+async fn test_retry_handle_reverts_if_origin_paused() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    mailbox
+        .methods()
+        .process_and_defer_handle(Bytes(vec![5u8; 100]), Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+
+    mailbox
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    let call = mailbox
+        .methods()
+        .retry_handle(Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("origin is paused")
+    );
+
+    // Once unpaused, the message can be handled
+    mailbox
+        .methods()
+        .unpause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    mailbox
+        .methods()
+        .retry_handle(Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_retry_handle_reverts_if_not_pending() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    let agent_message = test_message(&mailbox, recipient_id.clone(), false);
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    // Messages that were processed normally are never pending
+    mailbox
+        .methods()
+        .process(Bytes(vec![5u8; 100]), Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+
+    let call = mailbox
+        .methods()
+        .retry_handle(Bytes(agent_message.to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("!pending")
+    );
+}
+
+// ============ process_batch ============
+
+// Creates `count` inbound test messages with distinct nonces.
+fn test_inbound_messages(
+    mailbox: &Mailbox<WalletUnlocked>,
+    recipient: Bech32ContractId,
+    count: u32,
+) -> Vec<HyperlaneAgentMessage> {
+    (0..count)
+        .map(|nonce| HyperlaneAgentMessage {
+            nonce,
+            ..test_message(mailbox, recipient.clone(), false)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_process_batch() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    let agent_messages = test_inbound_messages(&mailbox, recipient_id.clone(), 3);
+    let metadatas = vec![Bytes(vec![5u8; 100]); agent_messages.len()];
+    let messages = agent_messages
+        .iter()
+        .map(|m| Bytes(m.to_vec()))
+        .collect::<Vec<_>>();
+
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    let call = mailbox
+        .methods()
+        .process_batch(metadatas, messages, true)
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(3_000_000))
+        .call()
+        .await
+        .unwrap();
+
+    assert_eq!(call.value, vec![ProcessResult::Processed(); 3]);
+
+    let events = call.get_logs_with_type::<ProcessEvent>().unwrap();
+    assert_eq!(
+        events,
+        agent_messages
+            .iter()
+            .map(|m| ProcessEvent {
+                message_id: h256_to_bits256(m.id()),
+                origin: m.origin,
+                sender: h256_to_bits256(m.sender),
+                recipient: h256_to_bits256(m.recipient),
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    for agent_message in agent_messages {
+        let delivered = mailbox
+            .methods()
+            .delivered(h256_to_bits256(agent_message.id()))
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+        assert!(delivered);
+    }
+}
+
+#[tokio::test]
+async fn test_process_batch_skips_failures() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    let agent_messages = test_inbound_messages(&mailbox, recipient_id.clone(), 2);
+    let wrong_destination_message = HyperlaneAgentMessage {
+        destination: TEST_REMOTE_DOMAIN,
+        ..agent_messages[1].clone()
+    };
+
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    // Process the first message ahead of the batch
+    mailbox
+        .methods()
+        .process(Bytes(vec![5u8; 100]), Bytes(agent_messages[0].to_vec()))
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+
+    let messages = vec![
+        Bytes(agent_messages[0].to_vec()),
+        Bytes(wrong_destination_message.to_vec()),
+        Bytes(agent_messages[1].to_vec()),
+    ];
+    let metadatas = vec![Bytes(vec![5u8; 100]); messages.len()];
+
+    let call = mailbox
+        .methods()
+        .process_batch(metadatas, messages, false)
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(3_000_000))
+        .call()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        call.value,
+        vec![
+            ProcessResult::AlreadyDelivered(),
+            ProcessResult::InvalidDestination(),
+            ProcessResult::Processed(),
+        ]
+    );
+
+    // Only the message that was processed is logged
+    let events = call.get_logs_with_type::<ProcessEvent>().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].message_id,
+        h256_to_bits256(agent_messages[1].id())
+    );
+}
+
+#[tokio::test]
+async fn test_process_batch_skips_module_reject() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    let agent_messages = test_inbound_messages(&mailbox, recipient_id.clone(), 2);
+    let messages = agent_messages
+        .iter()
+        .map(|m| Bytes(m.to_vec()))
+        .collect::<Vec<_>>();
+    let metadatas = vec![Bytes(vec![5u8; 100]); messages.len()];
+
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    let test_ism = TestInterchainSecurityModule::new(ism_id, mailbox.account());
+    test_ism.methods().set_accept(false).call().await.unwrap();
+
+    let call = mailbox
+        .methods()
+        .process_batch(metadatas, messages, false)
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(3_000_000))
+        .call()
+        .await
+        .unwrap();
+
+    assert_eq!(call.value, vec![ProcessResult::VerificationFailed(); 2]);
+
+    // Rejected messages are not marked as delivered
+    for agent_message in agent_messages {
+        let delivered = mailbox
+            .methods()
+            .delivered(h256_to_bits256(agent_message.id()))
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+        assert!(!delivered);
+    }
+}
+
+#[tokio::test]
+async fn test_process_batch_reverts_on_failure() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    let agent_messages = test_inbound_messages(&mailbox, recipient_id.clone(), 2);
+    // The second message is a duplicate of the first
+    let messages = vec![
+        Bytes(agent_messages[0].to_vec()),
+        Bytes(agent_messages[0].to_vec()),
+    ];
+    let metadatas = vec![Bytes(vec![5u8; 100]); messages.len()];
+
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    let call = mailbox
+        .methods()
+        .process_batch(metadatas, messages, true)
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(3_000_000))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("delivered")
+    );
+
+    // The whole batch was reverted
+    let delivered = mailbox
+        .methods()
+        .delivered(h256_to_bits256(agent_messages[0].id()))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(!delivered);
+}
+
+#[tokio::test]
+async fn test_process_batch_reverts_if_length_mismatch() {
+    let (mailbox, ism_id, recipient_id) = get_contract_instance().await;
+
+    let agent_messages = test_inbound_messages(&mailbox, recipient_id.clone(), 2);
+    let messages = agent_messages
+        .iter()
+        .map(|m| Bytes(m.to_vec()))
+        .collect::<Vec<_>>();
+
+    let contract_inputs = vec![ism_id.clone(), recipient_id];
+
+    let call = mailbox
+        .methods()
+        .process_batch(vec![Bytes(vec![5u8; 100])], messages, true)
+        .set_contract_ids(&contract_inputs)
+        .tx_params(TxParameters::default().set_gas_limit(3_000_000))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("!length")
+    );
+}
+
+// ============ pause ============
+
+#[tokio::test]
+async fn test_pause() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    mailbox.methods().pause().call().await.unwrap();
+
+    let paused: bool = mailbox
+        .methods()
+        .is_paused()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(paused);
+}
+
+#[tokio::test]
+async fn test_pause_reverts_if_not_owner() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    let call = mailbox
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .pause()
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_pause_by_pauser() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let pauser_wallet = set_up_pauser(&mailbox).await;
+
+    let pauser_mailbox = mailbox.with_account(pauser_wallet).unwrap();
+
+    // The pauser can pause the entire Mailbox and individual domains
+    pauser_mailbox.methods().pause().call().await.unwrap();
+    pauser_mailbox
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+    pauser_mailbox
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    let paused: bool = mailbox
+        .methods()
+        .is_paused()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(paused);
+
+    // But can't unpause any of them
+    let call = pauser_mailbox.methods().unpause().call().await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+
+    let call = pauser_mailbox
+        .methods()
+        .unpause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+
+    let call = pauser_mailbox
+        .methods()
+        .unpause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ unpause ============
+
+#[tokio::test]
+async fn test_unpause() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    // First pause...
+    mailbox.methods().pause().call().await.unwrap();
+
+    // Now unpause!
+    mailbox.methods().unpause().call().await.unwrap();
+
+    let paused: bool = mailbox
+        .methods()
+        .is_paused()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(!paused);
+}
+
+#[tokio::test]
+async fn test_unpause_reverts_if_not_owner() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    // First pause...
+    mailbox.methods().pause().call().await.unwrap();
+
+    let call = mailbox
+        .with_account(non_owner_wallet.clone())
+        .unwrap()
+        .methods()
+        .unpause()
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ pause_origin ============
+
+#[tokio::test]
+async fn test_pause_origin() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let call = mailbox
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.get_logs_with_type::<OriginPausedEvent>().unwrap(),
+        vec![OriginPausedEvent {
+            origin: TEST_REMOTE_DOMAIN,
+        }]
+    );
+
+    let paused = mailbox
+        .methods()
+        .is_origin_paused(TEST_REMOTE_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(paused);
+
+    // Other origins are unaffected
+    let paused = mailbox
+        .methods()
+        .is_origin_paused(TEST_REMOTE_DOMAIN + 1)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(!paused);
+
+    // Pausing a second time reverts
+    let call = mailbox
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("origin is already paused")
+    );
+}
+
+#[tokio::test]
+async fn test_pause_origin_by_pauser() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let pauser_wallet = set_up_pauser(&mailbox).await;
+
+    let call = mailbox
+        .with_account(pauser_wallet)
+        .unwrap()
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.get_logs_with_type::<OriginPausedEvent>().unwrap(),
+        vec![OriginPausedEvent {
+            origin: TEST_REMOTE_DOMAIN,
+        }]
+    );
+
+    let paused = mailbox
+        .methods()
+        .is_origin_paused(TEST_REMOTE_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(paused);
+}
+
+#[tokio::test]
+async fn test_pause_origin_reverts_if_not_pauser_or_owner() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    set_up_pauser(&mailbox).await;
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    let call = mailbox
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ unpause_origin ============
+
+#[tokio::test]
+async fn test_unpause_origin() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    // Unpausing when not paused reverts
+    let call = mailbox
+        .methods()
+        .unpause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("origin is not paused")
+    );
+
+    mailbox
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    let call = mailbox
+        .methods()
+        .unpause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.get_logs_with_type::<OriginUnpausedEvent>().unwrap(),
+        vec![OriginUnpausedEvent {
+            origin: TEST_REMOTE_DOMAIN,
+        }]
+    );
+
+    let paused = mailbox
+        .methods()
+        .is_origin_paused(TEST_REMOTE_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(!paused);
+}
+
+#[tokio::test]
+async fn test_unpause_origin_reverts_if_not_owner() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let pauser_wallet = set_up_pauser(&mailbox).await;
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    mailbox
+        .methods()
+        .pause_origin(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    // Neither the pauser nor any other non-owner can unpause
+    for wallet in [pauser_wallet, non_owner_wallet] {
+        let call = mailbox
+            .with_account(wallet)
+            .unwrap()
+            .methods()
+            .unpause_origin(TEST_REMOTE_DOMAIN)
+            .call()
+            .await;
+        assert!(call.is_err());
+        assert_eq!(
+            decode_revert_reason(call.err().unwrap()).unwrap(),
+            RevertReason::require("NotOwner")
+        );
+    }
+}
+
+// ============ pause_destination ============
+
+#[tokio::test]
+async fn test_pause_destination() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let call = mailbox
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.get_logs_with_type::<DestinationPausedEvent>().unwrap(),
+        vec![DestinationPausedEvent {
+            destination: TEST_REMOTE_DOMAIN,
+        }]
+    );
+
+    let paused = mailbox
+        .methods()
+        .is_destination_paused(TEST_REMOTE_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(paused);
+
+    // Pausing a second time reverts
+    let call = mailbox
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("destination is already paused")
+    );
+}
+
+#[tokio::test]
+async fn test_pause_destination_by_pauser() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let pauser_wallet = set_up_pauser(&mailbox).await;
+
+    let call = mailbox
+        .with_account(pauser_wallet)
+        .unwrap()
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.get_logs_with_type::<DestinationPausedEvent>().unwrap(),
+        vec![DestinationPausedEvent {
+            destination: TEST_REMOTE_DOMAIN,
+        }]
+    );
+
+    let paused = mailbox
+        .methods()
+        .is_destination_paused(TEST_REMOTE_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(paused);
+}
+
+#[tokio::test]
+async fn test_pause_destination_reverts_if_not_pauser_or_owner() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    set_up_pauser(&mailbox).await;
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    let call = mailbox
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ unpause_destination ============
+
+#[tokio::test]
+async fn test_unpause_destination() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    // Unpausing when not paused reverts
+    let call = mailbox
+        .methods()
+        .unpause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.unwrap_err()).unwrap(),
+        RevertReason::require("destination is not paused")
+    );
+
+    mailbox
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    let call = mailbox
+        .methods()
+        .unpause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        call.get_logs_with_type::<DestinationUnpausedEvent>()
+            .unwrap(),
+        vec![DestinationUnpausedEvent {
+            destination: TEST_REMOTE_DOMAIN,
+        }]
+    );
+
+    let paused = mailbox
+        .methods()
+        .is_destination_paused(TEST_REMOTE_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(!paused);
+}
+
+#[tokio::test]
+async fn test_unpause_destination_reverts_if_not_owner() {
+    let (mailbox, _, _) = get_contract_instance().await;
+
+    let pauser_wallet = set_up_pauser(&mailbox).await;
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    mailbox
+        .methods()
+        .pause_destination(TEST_REMOTE_DOMAIN)
+        .call()
+        .await
+        .unwrap();
+
+    // Neither the pauser nor any other non-owner can unpause
+    for wallet in [pauser_wallet, non_owner_wallet] {
+        let call = mailbox
+            .with_account(wallet)
+            .unwrap()
+            .methods()
+            .unpause_destination(TEST_REMOTE_DOMAIN)
+            .call()
+            .await;
+        assert!(call.is_err());
+        assert_eq!(
+            decode_revert_reason(call.err().unwrap()).unwrap(),
+            RevertReason::require("NotOwner")
+        );
+    }
+}
+
+// ============ set_default_ism ============
+
+#[tokio::test]
+async fn test_set_default_ism() {
+    let (mailbox, ism_id, _) = get_contract_instance().await;
+
+    // Sanity check the current default ISM is the one we expect
+    let default_ism = mailbox
+        .methods()
+        .get_default_ism()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(default_ism, ism_id.into());
+
+    let new_default_ism =
+        ContractId::from_str("0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe")
+            .unwrap();
+    assert_ne!(default_ism, new_default_ism);
+
+    let call = mailbox
+        .methods()
+        .set_default_ism(new_default_ism)
+        .call()
+        .await
+        .unwrap();
+    // Ensure the event was logged
+    assert_eq!(
+        call.get_logs_with_type::<DefaultIsmSetEvent>().unwrap(),
+        vec![DefaultIsmSetEvent {
+            module: new_default_ism,
+        }]
+    );
+    // And make sure the default ISM was really updated
+    let default_ism = mailbox
+        .methods()
+        .get_default_ism()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(default_ism, new_default_ism);
+}
+
+#[tokio::test]
+async fn test_set_default_ism_reverts_if_not_owner() {
+    let (mailbox, _, _) = get_contract_instance().await;
+    let new_default_ism =
+        ContractId::from_str("0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe")
+            .unwrap();
+
+    let non_owner_wallet =
         funded_wallet_with_private_key(&mailbox.account(), NON_OWNER_PRIVATE_KEY)
             .await
             .unwrap();
@@ -618,5 +1721,8 @@ async fn test_set_default_ism_reverts_if_not_owner() {
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner",);
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner"),
+    );
 }
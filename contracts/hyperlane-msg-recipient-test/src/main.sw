@@ -8,6 +8,10 @@ abi TestMessageRecipient {
     #[storage(read)]
     fn handled() -> bool;
 
+    /// Sets whether `handle` should revert, to simulate a failing recipient.
+    #[storage(write)]
+    fn set_should_revert(should_revert: bool);
+
     /// TODO: remove
     /// This is a temporary function to allow us to send messages
     /// using fuels-ts, which doesn't yet support Bytes.
@@ -23,6 +27,7 @@ const ZERO_ID: ContractId = ContractId {
 storage {
     module: ContractId = ZERO_ID,
     handled: bool = false,
+    should_revert: bool = false,
 }
 
 impl MessageRecipient for Contract {
@@ -30,6 +35,7 @@ impl MessageRecipient for Contract {
     fn handle(origin: u32, sender: b256, message_body: Bytes) {
         // To ignore a compiler warning that no storage reads are made.
         let _ = storage.module;
+        require(!storage.should_revert, "handle reverted");
 
         storage.handled = true;
     }
@@ -46,6 +52,11 @@ impl TestMessageRecipient for Contract {
         storage.handled
     }
 
+    #[storage(write)]
+    fn set_should_revert(should_revert: bool) {
+        storage.should_revert = should_revert;
+    }
+
     fn dispatch(
         body: Vec<u8>,
         mailbox_id: b256,
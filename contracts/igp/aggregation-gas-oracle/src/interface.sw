@@ -0,0 +1,34 @@
+library;
+
+/// Logged when a source oracle is added.
+pub struct SourceAddedEvent {
+    source: b256,
+}
+
+/// Logged when a source oracle is removed.
+pub struct SourceRemovedEvent {
+    source: b256,
+}
+
+/// Logged when the max number of missing sources is set.
+pub struct MaxMissingSourcesSetEvent {
+    max_missing_sources: u64,
+}
+
+/// A gas oracle that aggregates the gas data of several source oracles.
+abi AggregationGasOracle {
+    #[storage(read)]
+    fn sources() -> Vec<b256>;
+
+    #[storage(read, write)]
+    fn add_source(source: b256);
+
+    #[storage(read, write)]
+    fn remove_source(source: b256);
+
+    #[storage(read)]
+    fn max_missing_sources() -> u64;
+
+    #[storage(read, write)]
+    fn set_max_missing_sources(max_missing_sources: u64);
+}
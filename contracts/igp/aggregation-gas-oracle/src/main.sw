@@ -0,0 +1,256 @@
+contract;
+
+mod interface;
+
+use std::{logging::log, storage::StorageVec, u128::U128};
+
+use hyperlane_interfaces::{igp::{FallibleGasOracle, GasOracle, RemoteGasData}, ownable::{Ownable}};
+
+use ownership::{data_structures::State, only_owner, owner, set_ownership, transfer_ownership};
+
+use std_lib_extended::option::*;
+
+use interface::{
+    AggregationGasOracle,
+    MaxMissingSourcesSetEvent,
+    SourceAddedEvent,
+    SourceRemovedEvent,
+};
+
+storage {
+    /// The source oracles, which must implement `FallibleGasOracle`.
+    sources: StorageVec<b256> = StorageVec {},
+    /// The max number of sources that may be missing or stale when aggregating.
+    max_missing_sources: u64 = 0,
+}
+
+/// A gas oracle that returns the median exchange rate and gas price reported
+/// by its source oracles, so that a single compromised source can't distort
+/// quotes. Sources with missing or stale gas data, or that disagree on the
+/// remote token's decimals, are ignored, up to the configured max number of
+/// missing sources.
+impl GasOracle for Contract {
+    /// Gets the median gas data reported by the sources.
+    /// Reverts if too many sources are missing gas data. Sources that disagree
+    /// with the most commonly reported token decimals are treated as missing.
+    #[storage(read)]
+    fn get_remote_gas_data(domain: u32) -> RemoteGasData {
+        aggregate_remote_gas_data(domain).expect("too many missing sources")
+    }
+}
+
+impl FallibleGasOracle for Contract {
+    /// Gets the median gas data reported by the sources, or None if too
+    /// many sources are missing gas data.
+    #[storage(read)]
+    fn try_get_remote_gas_data(domain: u32) -> Option<RemoteGasData> {
+        aggregate_remote_gas_data(domain)
+    }
+}
+
+impl AggregationGasOracle for Contract {
+    /// Gets the source oracles.
+    #[storage(read)]
+    fn sources() -> Vec<b256> {
+        let mut sources = Vec::new();
+        let mut i = 0;
+        let count = storage.sources.len();
+        while i < count {
+            sources.push(storage.sources.get(i).unwrap());
+            i += 1;
+        }
+        sources
+    }
+
+    /// Adds a source oracle. Only callable by the owner.
+    /// Reverts if the source has already been added.
+    #[storage(read, write)]
+    fn add_source(source: b256) {
+        only_owner();
+        require(index_of_source(source).is_none(), "source already added");
+
+        storage.sources.push(source);
+        log(SourceAddedEvent { source });
+    }
+
+    /// Removes a source oracle. Only callable by the owner.
+    /// Reverts if the source has not been added.
+    #[storage(read, write)]
+    fn remove_source(source: b256) {
+        only_owner();
+        let index = index_of_source(source).expect("source not found");
+
+        let _ = storage.sources.swap_remove(index);
+        log(SourceRemovedEvent { source });
+    }
+
+    /// Gets the max number of sources that may be missing or stale.
+    #[storage(read)]
+    fn max_missing_sources() -> u64 {
+        storage.max_missing_sources
+    }
+
+    /// Sets the max number of sources that may be missing or stale.
+    /// Only callable by the owner.
+    #[storage(read, write)]
+    fn set_max_missing_sources(max_missing_sources: u64) {
+        only_owner();
+
+        storage.max_missing_sources = max_missing_sources;
+        log(MaxMissingSourcesSetEvent {
+            max_missing_sources,
+        });
+    }
+}
+
+impl Ownable for Contract {
+    /// Gets the current owner.
+    #[storage(read)]
+    fn owner() -> State {
+        owner()
+    }
+
+    /// Transfers ownership to `new_owner`.
+    /// Reverts if the msg_sender is not the current owner.
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        transfer_ownership(new_owner);
+    }
+
+    /// Initializes ownership to `new_owner`.
+    /// Reverts if owner already initialized.
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        set_ownership(new_owner);
+    }
+}
+
+/// Gets the index of a source in storage, or None if it has not been added.
+#[storage(read)]
+fn index_of_source(source: b256) -> Option<u64> {
+    let mut i = 0;
+    let count = storage.sources.len();
+    while i < count {
+        if storage.sources.get(i).unwrap() == source {
+            return Option::Some(i);
+        }
+        i += 1;
+    }
+    Option::None
+}
+
+/// Gets the median gas data reported by the sources, or None if more than
+/// the max number of sources are missing gas data or there are no sources.
+/// Sources that disagree with the most commonly reported token decimals are
+/// treated as missing, so a single misconfigured source can't prevent quoting.
+#[storage(read)]
+fn aggregate_remote_gas_data(domain: u32) -> Option<RemoteGasData> {
+    let count = storage.sources.len();
+
+    let mut reported: Vec<RemoteGasData> = Vec::new();
+    let mut i = 0;
+    while i < count {
+        let source = abi(FallibleGasOracle, storage.sources.get(i).unwrap());
+        match source.try_get_remote_gas_data(domain) {
+            Option::Some(gas_data) => reported.push(gas_data),
+            Option::None => {},
+        }
+        i += 1;
+    }
+
+    if reported.is_empty() {
+        return Option::None;
+    }
+    let token_decimals = most_common_token_decimals(reported);
+
+    let mut token_exchange_rates: Vec<U128> = Vec::new();
+    let mut gas_prices: Vec<U128> = Vec::new();
+    let mut i = 0;
+    while i < reported.len() {
+        let gas_data = reported.get(i).unwrap();
+        if gas_data.token_decimals == token_decimals {
+            insert_sorted(token_exchange_rates, gas_data.token_exchange_rate);
+            insert_sorted(gas_prices, gas_data.gas_price);
+        }
+        i += 1;
+    }
+
+    let present = token_exchange_rates.len();
+    if count - present > storage.max_missing_sources {
+        return Option::None;
+    }
+
+    Option::Some(RemoteGasData {
+        token_exchange_rate: median(token_exchange_rates),
+        gas_price: median(gas_prices),
+        token_decimals,
+    })
+}
+
+/// Gets the token decimals reported by the most sources in `reported`, which
+/// must be non-empty. Ties are broken in favor of the earliest reported.
+fn most_common_token_decimals(reported: Vec<RemoteGasData>) -> u8 {
+    let len = reported.len();
+    let mut most_common = reported.get(0).unwrap().token_decimals;
+    let mut most_common_count = 0;
+
+    let mut i = 0;
+    while i < len {
+        let token_decimals = reported.get(i).unwrap().token_decimals;
+        let mut token_decimals_count = 0;
+        let mut j = 0;
+        while j < len {
+            if reported.get(j).unwrap().token_decimals == token_decimals {
+                token_decimals_count += 1;
+            }
+            j += 1;
+        }
+        if token_decimals_count > most_common_count {
+            most_common = token_decimals;
+            most_common_count = token_decimals_count;
+        }
+        i += 1;
+    }
+    most_common
+}
+
+/// Inserts `value` into `values`, which must already be sorted in
+/// ascending order, such that `values` remains sorted.
+fn insert_sorted(ref mut values: Vec<U128>, value: U128) {
+    let mut index = 0;
+    let len = values.len();
+    while index < len && !(values.get(index).unwrap() > value) {
+        index += 1;
+    }
+    values.insert(index, value);
+}
+
+/// Gets the median of `values`, which must be sorted in ascending order
+/// and non-empty. For an even number of values, the lower of the two
+/// middle values is returned.
+fn median(values: Vec<U128>) -> U128 {
+    values.get((values.len() - 1) / 2).unwrap()
+}
+
+#[test()]
+fn test_insert_sorted_and_median() {
+    let mut values: Vec<U128> = Vec::new();
+
+    insert_sorted(values, U128::from((0, 5)));
+    assert(median(values) == U128::from((0, 5)));
+
+    insert_sorted(values, U128::from((0, 1)));
+    insert_sorted(values, U128::from((0, 9)));
+    // [1, 5, 9]
+    assert(values.get(0).unwrap() == U128::from((0, 1)));
+    assert(values.get(2).unwrap() == U128::from((0, 9)));
+    assert(median(values) == U128::from((0, 5)));
+
+    insert_sorted(values, U128::from((1, 0)));
+    // [1, 5, 9, 2^64], the lower middle value is used
+    assert(median(values) == U128::from((0, 5)));
+
+    insert_sorted(values, U128::from((0, 5)));
+    // [1, 5, 5, 9, 2^64]
+    assert(median(values) == U128::from((0, 5)));
+}
@@ -0,0 +1,570 @@
+use fuels::{
+    prelude::*,
+    tx::{ContractId, Salt},
+    types::{Bits256, Identity},
+};
+
+use test_utils::{decode_revert_reason, funded_wallet_with_private_key, RevertReason};
+
+// Load abi from json
+abigen!(Contract(
+    name = "AggregationGasOracle",
+    abi = "contracts/igp/aggregation-gas-oracle/out/debug/aggregation-gas-oracle-abi.json"
+));
+
+mod gas_oracle {
+    use fuels::prelude::abigen;
+
+    // Load abi from json
+    abigen!(Contract(
+        name = "StorageGasOracle",
+        abi = "contracts/igp/storage-gas-oracle/out/debug/storage-gas-oracle-abi.json"
+    ));
+
+    impl From<u64> for U128 {
+        fn from(value: u64) -> Self {
+            Self {
+                upper: 0,
+                lower: value,
+            }
+        }
+    }
+}
+
+// The generated U128 struct from abigen doesn't have a very nice way of
+// converting from u64
+impl From<u64> for U128 {
+    fn from(value: u64) -> Self {
+        Self {
+            upper: 0,
+            lower: value,
+        }
+    }
+}
+
+use gas_oracle::{RemoteGasDataConfig, StorageGasOracle};
+
+const NON_OWNER_PRIVATE_KEY: &str =
+    "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
+
+const TEST_DOMAIN: u32 = 11111;
+const TEST_TOKEN_DECIMALS: u8 = 18;
+
+async fn get_contract_instances(
+    source_count: u8,
+) -> (
+    AggregationGasOracle<WalletUnlocked>,
+    Vec<StorageGasOracle<WalletUnlocked>>,
+) {
+    // Launch a local network and deploy the contracts
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(
+            Some(1),             /* Single wallet */
+            Some(1),             /* Single coin (UTXO) */
+            Some(1_000_000_000), /* Amount per coin */
+        ),
+        None,
+        None,
+    )
+    .await;
+    let wallet = wallets.pop().unwrap();
+
+    let owner_identity = Identity::Address(wallet.address().into());
+
+    let aggregation_gas_oracle_id = Contract::deploy(
+        "./out/debug/aggregation-gas-oracle.bin",
+        &wallet,
+        DeployConfiguration::default().set_storage_configuration(StorageConfiguration::new(
+            "./out/debug/aggregation-gas-oracle-storage_slots.json".to_string(),
+            vec![],
+        )),
+    )
+    .await
+    .unwrap();
+    let aggregation_gas_oracle =
+        AggregationGasOracle::new(aggregation_gas_oracle_id, wallet.clone());
+
+    aggregation_gas_oracle
+        .methods()
+        .set_ownership(owner_identity.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let mut sources = vec![];
+    for i in 0..source_count {
+        // Each source is deployed with a different salt to get a unique contract ID
+        let source_id = Contract::deploy(
+            "../storage-gas-oracle/out/debug/storage-gas-oracle.bin",
+            &wallet,
+            DeployConfiguration::default()
+                .set_storage_configuration(StorageConfiguration::new(
+                    "../storage-gas-oracle/out/debug/storage-gas-oracle-storage_slots.json"
+                        .to_string(),
+                    vec![],
+                ))
+                .set_salt(Salt::from([i; 32])),
+        )
+        .await
+        .unwrap();
+        let source = StorageGasOracle::new(source_id.clone(), wallet.clone());
+
+        source
+            .methods()
+            .set_ownership(owner_identity.clone())
+            .call()
+            .await
+            .unwrap();
+
+        aggregation_gas_oracle
+            .methods()
+            .add_source(Bits256(source_id.hash().into()))
+            .call()
+            .await
+            .unwrap();
+
+        sources.push(source);
+    }
+
+    (aggregation_gas_oracle, sources)
+}
+
+async fn set_source_gas_data(
+    source: &StorageGasOracle<WalletUnlocked>,
+    token_exchange_rate: u64,
+    gas_price: u64,
+    token_decimals: u8,
+) {
+    source
+        .methods()
+        .set_remote_gas_data_configs(vec![RemoteGasDataConfig {
+            domain: TEST_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: token_exchange_rate.into(),
+                gas_price: gas_price.into(),
+                token_decimals,
+            },
+        }])
+        .call()
+        .await
+        .unwrap();
+}
+
+fn source_contract_ids(sources: &[StorageGasOracle<WalletUnlocked>]) -> Vec<Bech32ContractId> {
+    sources
+        .iter()
+        .map(|source| source.contract_id().clone())
+        .collect()
+}
+
+fn source_ids(sources: &[StorageGasOracle<WalletUnlocked>]) -> Vec<Bits256> {
+    sources
+        .iter()
+        .map(|source| Bits256(ContractId::from(source.contract_id()).into()))
+        .collect()
+}
+
+// ============ get_remote_gas_data ============
+
+#[tokio::test]
+async fn test_get_remote_gas_data_returns_median() {
+    let (oracle, sources) = get_contract_instances(3).await;
+
+    set_source_gas_data(&sources[0], 100, 30, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[1], 300, 10, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[2], 200, 20, TEST_TOKEN_DECIMALS).await;
+
+    // The median of each value is taken independently
+    let remote_gas_data = oracle
+        .methods()
+        .get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        remote_gas_data,
+        RemoteGasData {
+            token_exchange_rate: 200.into(),
+            gas_price: 20.into(),
+            token_decimals: TEST_TOKEN_DECIMALS,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_uses_lower_median_for_even_count() {
+    let (oracle, sources) = get_contract_instances(4).await;
+
+    set_source_gas_data(&sources[0], 100, 40, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[1], 400, 10, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[2], 200, 30, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[3], 300, 20, TEST_TOKEN_DECIMALS).await;
+
+    let remote_gas_data = oracle
+        .methods()
+        .get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        remote_gas_data,
+        RemoteGasData {
+            token_exchange_rate: 200.into(),
+            gas_price: 20.into(),
+            token_decimals: TEST_TOKEN_DECIMALS,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_tolerates_missing_sources() {
+    let (oracle, sources) = get_contract_instances(3).await;
+
+    oracle
+        .methods()
+        .set_max_missing_sources(1)
+        .call()
+        .await
+        .unwrap();
+
+    // The third source has no gas data set
+    set_source_gas_data(&sources[0], 100, 10, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[1], 300, 30, TEST_TOKEN_DECIMALS).await;
+
+    let remote_gas_data = oracle
+        .methods()
+        .get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        remote_gas_data,
+        RemoteGasData {
+            token_exchange_rate: 100.into(),
+            gas_price: 10.into(),
+            token_decimals: TEST_TOKEN_DECIMALS,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_reverts_if_too_many_missing_sources() {
+    let (oracle, sources) = get_contract_instances(3).await;
+
+    oracle
+        .methods()
+        .set_max_missing_sources(1)
+        .call()
+        .await
+        .unwrap();
+
+    set_source_gas_data(&sources[0], 100, 10, TEST_TOKEN_DECIMALS).await;
+
+    let call = oracle
+        .methods()
+        .get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("too many missing sources")
+    );
+
+    // None is returned rather than reverting
+    let remote_gas_data = oracle
+        .methods()
+        .try_get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(remote_gas_data, None);
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_reverts_if_no_sources() {
+    let (oracle, _) = get_contract_instances(0).await;
+
+    let call = oracle
+        .methods()
+        .get_remote_gas_data(TEST_DOMAIN)
+        .simulate()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("too many missing sources")
+    );
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_ignores_token_decimals_mismatch() {
+    let (oracle, sources) = get_contract_instances(3).await;
+
+    oracle
+        .methods()
+        .set_max_missing_sources(1)
+        .call()
+        .await
+        .unwrap();
+
+    // The first source is misconfigured with the wrong decimals
+    set_source_gas_data(&sources[0], 1, 1, 9).await;
+    set_source_gas_data(&sources[1], 100, 10, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[2], 300, 30, TEST_TOKEN_DECIMALS).await;
+
+    let remote_gas_data = oracle
+        .methods()
+        .get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        remote_gas_data,
+        RemoteGasData {
+            token_exchange_rate: 100.into(),
+            gas_price: 10.into(),
+            token_decimals: TEST_TOKEN_DECIMALS,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_reverts_if_too_many_token_decimals_mismatches() {
+    let (oracle, sources) = get_contract_instances(3).await;
+
+    oracle
+        .methods()
+        .set_max_missing_sources(1)
+        .call()
+        .await
+        .unwrap();
+
+    // One source is missing and another disagrees on the decimals
+    set_source_gas_data(&sources[0], 100, 10, TEST_TOKEN_DECIMALS).await;
+    set_source_gas_data(&sources[1], 100, 10, 9).await;
+
+    let call = oracle
+        .methods()
+        .get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("too many missing sources")
+    );
+
+    // None is returned rather than reverting
+    let remote_gas_data = oracle
+        .methods()
+        .try_get_remote_gas_data(TEST_DOMAIN)
+        .set_contract_ids(&source_contract_ids(&sources))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(remote_gas_data, None);
+}
+
+// ============ add_source ============
+
+#[tokio::test]
+async fn test_add_source() {
+    let (oracle, sources) = get_contract_instances(2).await;
+
+    assert_eq!(
+        oracle.methods().sources().simulate().await.unwrap().value,
+        source_ids(&sources),
+    );
+
+    let new_source = Bits256([1u8; 32]);
+    let call = oracle
+        .methods()
+        .add_source(new_source)
+        .call()
+        .await
+        .unwrap();
+
+    let events = call.get_logs_with_type::<SourceAddedEvent>().unwrap();
+    assert_eq!(events, vec![SourceAddedEvent { source: new_source }]);
+
+    let mut expected_sources = source_ids(&sources);
+    expected_sources.push(new_source);
+    assert_eq!(
+        oracle.methods().sources().simulate().await.unwrap().value,
+        expected_sources,
+    );
+}
+
+#[tokio::test]
+async fn test_add_source_reverts_if_already_added() {
+    let (oracle, sources) = get_contract_instances(1).await;
+
+    let call = oracle
+        .methods()
+        .add_source(source_ids(&sources)[0])
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("source already added")
+    );
+}
+
+#[tokio::test]
+async fn test_add_source_reverts_if_not_owner() {
+    let (oracle, _) = get_contract_instances(0).await;
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = oracle
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .add_source(Bits256([1u8; 32]))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ remove_source ============
+
+#[tokio::test]
+async fn test_remove_source() {
+    let (oracle, sources) = get_contract_instances(3).await;
+    let ids = source_ids(&sources);
+
+    let call = oracle.methods().remove_source(ids[0]).call().await.unwrap();
+
+    let events = call.get_logs_with_type::<SourceRemovedEvent>().unwrap();
+    assert_eq!(events, vec![SourceRemovedEvent { source: ids[0] }]);
+
+    // The last source is swapped into the removed source's position
+    assert_eq!(
+        oracle.methods().sources().simulate().await.unwrap().value,
+        vec![ids[2], ids[1]],
+    );
+}
+
+#[tokio::test]
+async fn test_remove_source_reverts_if_not_found() {
+    let (oracle, _) = get_contract_instances(1).await;
+
+    let call = oracle
+        .methods()
+        .remove_source(Bits256([1u8; 32]))
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("source not found")
+    );
+}
+
+#[tokio::test]
+async fn test_remove_source_reverts_if_not_owner() {
+    let (oracle, sources) = get_contract_instances(1).await;
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = oracle
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .remove_source(source_ids(&sources)[0])
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ set_max_missing_sources ============
+
+#[tokio::test]
+async fn test_set_max_missing_sources() {
+    let (oracle, _) = get_contract_instances(0).await;
+
+    assert_eq!(
+        oracle
+            .methods()
+            .max_missing_sources()
+            .simulate()
+            .await
+            .unwrap()
+            .value,
+        0
+    );
+
+    let call = oracle
+        .methods()
+        .set_max_missing_sources(2)
+        .call()
+        .await
+        .unwrap();
+
+    let events = call
+        .get_logs_with_type::<MaxMissingSourcesSetEvent>()
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![MaxMissingSourcesSetEvent {
+            max_missing_sources: 2
+        }]
+    );
+
+    assert_eq!(
+        oracle
+            .methods()
+            .max_missing_sources()
+            .simulate()
+            .await
+            .unwrap()
+            .value,
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_set_max_missing_sources_reverts_if_not_owner() {
+    let (oracle, _) = get_contract_instances(0).await;
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = oracle
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_max_missing_sources(2)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
@@ -5,9 +5,12 @@ pub struct BeneficiarySetEvent {
     beneficiary: Identity,
 }
 
-/// Logged when the balance is claimed and sent to the beneficiary.
+/// Logged when an amount of an asset is claimed.
+/// `beneficiary` is the identity the funds were sent to, which is a recipient
+/// chosen by the owner when claimed using `claim_to`.
 pub struct ClaimEvent {
     beneficiary: Identity,
+    asset_id: ContractId,
     amount: u64,
 }
 
@@ -17,6 +20,34 @@ pub struct GasOracleSetEvent {
     gas_oracle: b256,
 }
 
+/// Logged when an asset is configured to be accepted as payment.
+pub struct PaymentAssetSetEvent {
+    asset_id: ContractId,
+    decimals: u8,
+}
+
+/// Logged when the gas oracle is set for an asset and domain.
+pub struct AssetGasOracleSetEvent {
+    asset_id: ContractId,
+    domain: u32,
+    gas_oracle: b256,
+}
+
+/// Logged instead of a `GasPaymentEvent` when a payment is made in an asset
+/// other than the base asset. The `payment` is denominated in `asset_id`.
+pub struct AssetGasPaymentEvent {
+    message_id: b256,
+    asset_id: ContractId,
+    gas_amount: u64,
+    payment: u64,
+}
+
+/// Functions to view the total gas paid recorded for messages.
+abi GasPaymentLedger {
+    #[storage(read)]
+    fn gas_paid(message_id: b256) -> u64;
+}
+
 /// Functions specific to on chain fee quoting.
 abi OnChainFeeQuoting {
     #[storage(read, write)]
@@ -26,6 +57,29 @@ abi OnChainFeeQuoting {
     fn gas_oracle(domain: u32) -> Option<b256>;
 }
 
+/// Functions for paying for interchain gas in assets other than the base asset.
+/// Each accepted asset has its own gas oracle per domain, whose exchange rates
+/// are relative to that asset.
+abi MultiAssetFeeQuoting {
+    #[storage(read, write)]
+    fn set_payment_asset(asset_id: ContractId, decimals: u8);
+
+    #[storage(read)]
+    fn payment_asset_decimals(asset_id: ContractId) -> Option<u8>;
+
+    #[storage(read)]
+    fn payment_assets() -> Vec<ContractId>;
+
+    #[storage(read, write)]
+    fn set_asset_gas_oracle(asset_id: ContractId, domain: u32, gas_oracle: b256);
+
+    #[storage(read)]
+    fn asset_gas_oracle(asset_id: ContractId, domain: u32) -> Option<b256>;
+
+    #[storage(read)]
+    fn quote_gas_payment_in(asset_id: ContractId, destination_domain: u32, gas_amount: u64) -> u64;
+}
+
 /// Allows the beneficiary to claim the contract's balance.
 abi Claimable {
     #[storage(read)]
@@ -34,6 +88,15 @@ abi Claimable {
     #[storage(read, write)]
     fn set_beneficiary(beneficiary: Identity);
 
-    #[storage(read)]
+    #[storage(read, write)]
     fn claim();
+
+    #[storage(read, write)]
+    fn claim_amount(asset_id: ContractId, amount: u64);
+
+    #[storage(read, write)]
+    fn claim_to(asset_id: ContractId, amount: u64, recipient: Identity);
+
+    #[storage(read)]
+    fn total_claimed(asset_id: ContractId) -> u64;
 }
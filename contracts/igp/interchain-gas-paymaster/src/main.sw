@@ -4,12 +4,16 @@ mod interface;
 
 use std::{
     call_frames::msg_asset_id,
-    constants::BASE_ASSET_ID,
+    constants::{
+        BASE_ASSET_ID,
+        ZERO_B256,
+    },
     context::{
         msg_amount,
         this_balance,
     },
     logging::log,
+    storage::StorageVec,
     token::transfer,
     u128::U128,
     u256::U256,
@@ -29,27 +33,52 @@ use hyperlane_interfaces::{
     ownable::Ownable,
 };
 
-use interface::{BeneficiarySetEvent, Claimable, ClaimEvent, GasOracleSetEvent, OnChainFeeQuoting};
+use interface::{
+    AssetGasOracleSetEvent,
+    AssetGasPaymentEvent,
+    BeneficiarySetEvent,
+    Claimable,
+    ClaimEvent,
+    GasOracleSetEvent,
+    GasPaymentLedger,
+    MultiAssetFeeQuoting,
+    OnChainFeeQuoting,
+    PaymentAssetSetEvent,
+};
 
 /// The scale of a token exchange rate. 1e19.
 const TOKEN_EXCHANGE_RATE_SCALE: u64 = 10_000_000_000_000_000_000;
 
 const BASE_ASSET_DECIMALS: u8 = 9;
 
-// TODO: set this at compile / deploy time.
-// NOTE for now this is temporarily set to the address of a PUBLICLY KNOWN
-// PRIVATE KEY, which is the first default account when running fuel-client locally.
-const INITIAL_BENEFICIARY = Identity::Address(Address::from(0x6b63804cfbf9856e68e5b6e7aef238dc8311ec55bec04df774003a2c96e0418e));
+configurable {
+    /// The beneficiary until one is set by the owner. Expected to be set at deploy time.
+    /// Defaults to the zero address, which `claim` refuses to send funds to.
+    INITIAL_BENEFICIARY: Identity = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)),
+}
 
 storage {
     gas_oracles: StorageMap<u32, b256> = StorageMap {},
-    beneficiary: Identity = INITIAL_BENEFICIARY,
+    /// The beneficiary set by the owner. If None, `INITIAL_BENEFICIARY` is used.
+    beneficiary: Option<Identity> = Option::None,
+    /// Gas oracles for assets other than the base asset, keyed by (asset, domain).
+    asset_gas_oracles: StorageMap<(ContractId, u32), b256> = StorageMap {},
+    /// The decimals of assets other than the base asset that are accepted as payment.
+    asset_decimals: StorageMap<ContractId, u8> = StorageMap {},
+    /// All assets other than the base asset that have been accepted as payment.
+    payment_assets: StorageVec<ContractId> = StorageVec {},
+    /// The total amount of each asset that has been claimed.
+    total_claimed: StorageMap<ContractId, u64> = StorageMap {},
+    /// Message ID -> total gas amount paid for.
+    gas_paid: StorageMap<b256, u64> = StorageMap {},
 }
 
 impl InterchainGasPaymaster for Contract {
-    /// Pays for a message's interchain gas in the base asset.
+    /// Pays for a message's interchain gas in the base asset, or any other
+    /// asset configured using `set_payment_asset`.
     /// The payment amount must be at least the amount returned by
-    /// `quote_gas_payment`.
+    /// `quote_gas_payment_in` for the asset. Overpayment is refunded in the
+    /// same asset. Reverts if the gas amount is zero.
     ///
     /// ### Arguments
     ///
@@ -58,8 +87,8 @@ impl InterchainGasPaymaster for Contract {
     /// * `gas_amount` - The amount of destination gas to pay for.
     /// * `refund_address` - The address to refund any overpayment to.
     ///
-    /// While this IGP doesn't make any storage writes, the interface allows
-    /// this for future IGP implementations that may need to write to storage.
+    /// The total gas amount paid for the message is recorded in storage, and
+    /// can be viewed using `gas_paid`. Individual payments are only logged.
     #[storage(read, write)]
     #[payable]
     fn pay_for_gas(
@@ -68,10 +97,11 @@ impl InterchainGasPaymaster for Contract {
         gas_amount: u64,
         refund_address: Identity,
     ) {
-        // Only the base asset can be used to pay for gas.
-        require(msg_asset_id() == BASE_ASSET_ID, "interchain gas payment must be in base asset");
+        require(gas_amount > 0, "zero gas amount");
 
-        let required_payment = quote_gas_payment(destination_domain, gas_amount);
+        let asset_id = msg_asset_id();
+        // Reverts if the asset is not accepted as payment.
+        let required_payment = quote_gas_payment_in(asset_id, destination_domain, gas_amount);
 
         let payment_amount = msg_amount();
         require(payment_amount >= required_payment, "insufficient interchain gas payment");
@@ -79,14 +109,27 @@ impl InterchainGasPaymaster for Contract {
         // Refund any overpayment.
         let overpayment = payment_amount - required_payment;
         if (overpayment > 0) {
-            transfer(overpayment, BASE_ASSET_ID, refund_address);
+            transfer(overpayment, asset_id, refund_address);
         }
 
-        log(GasPaymentEvent {
-            message_id,
-            gas_amount,
-            payment: required_payment,
-        });
+        record_gas_paid(message_id, gas_amount);
+
+        // A `GasPaymentEvent`'s payment is denominated in the base asset,
+        // so payments in other assets are logged separately.
+        if asset_id == BASE_ASSET_ID {
+            log(GasPaymentEvent {
+                message_id,
+                gas_amount,
+                payment: required_payment,
+            });
+        } else {
+            log(AssetGasPaymentEvent {
+                message_id,
+                asset_id,
+                gas_amount,
+                payment: required_payment,
+            });
+        }
     }
 
     /// Quotes the required interchain gas payment to be paid in the base asset.
@@ -114,7 +157,7 @@ impl Claimable for Contract {
     /// Gets the current beneficiary.
     #[storage(read)]
     fn beneficiary() -> Identity {
-        storage.beneficiary
+        beneficiary()
     }
 
     /// Sets the beneficiary to `beneficiary`. Only callable by the owner.
@@ -122,21 +165,48 @@ impl Claimable for Contract {
     fn set_beneficiary(beneficiary: Identity) {
         only_owner();
 
-        storage.beneficiary = beneficiary;
+        storage.beneficiary = Option::Some(beneficiary);
         log(BeneficiarySetEvent { beneficiary });
     }
 
-    /// Sends all base asset funds to the beneficiary. Callable by anyone.
-    #[storage(read)]
+    /// Sends all funds of the base asset and every asset accepted as payment
+    /// to the beneficiary. Callable by anyone.
+    #[storage(read, write)]
     fn claim() {
-        let beneficiary = storage.beneficiary;
-        let balance = this_balance(BASE_ASSET_ID);
-        transfer(balance, BASE_ASSET_ID, storage.beneficiary);
+        claim_asset(BASE_ASSET_ID);
+
+        let mut i = 0;
+        let count = storage.payment_assets.len();
+        while i < count {
+            let asset_id = storage.payment_assets.get(i).unwrap();
+            // Avoid logging claims for assets that have never been paid with.
+            if this_balance(asset_id) > 0 {
+                claim_asset(asset_id);
+            }
+            i += 1;
+        }
+    }
 
-        log(ClaimEvent {
-            beneficiary,
-            amount: balance,
-        });
+    /// Sends `amount` of `asset_id` to the beneficiary. Callable by anyone.
+    /// Reverts if the contract's balance of the asset is insufficient.
+    #[storage(read, write)]
+    fn claim_amount(asset_id: ContractId, amount: u64) {
+        send_claim(asset_id, amount, beneficiary());
+    }
+
+    /// Sends `amount` of `asset_id` to `recipient`. Only callable by the owner.
+    /// Reverts if the contract's balance of the asset is insufficient.
+    #[storage(read, write)]
+    fn claim_to(asset_id: ContractId, amount: u64, recipient: Identity) {
+        only_owner();
+
+        send_claim(asset_id, amount, recipient);
+    }
+
+    /// Gets the total amount of `asset_id` that has ever been claimed.
+    #[storage(read)]
+    fn total_claimed(asset_id: ContractId) -> u64 {
+        storage.total_claimed.get(asset_id).unwrap_or(0)
     }
 }
 
@@ -182,6 +252,146 @@ impl OnChainFeeQuoting for Contract {
     }
 }
 
+impl MultiAssetFeeQuoting for Contract {
+    /// Accepts `asset_id` as payment, with the given number of decimals.
+    /// Only callable by the owner. The base asset is always accepted
+    /// and cannot be configured.
+    #[storage(read, write)]
+    fn set_payment_asset(asset_id: ContractId, decimals: u8) {
+        only_owner();
+        require(asset_id != BASE_ASSET_ID, "cannot configure base asset");
+
+        if storage.asset_decimals.get(asset_id).is_none() {
+            storage.payment_assets.push(asset_id);
+        }
+        storage.asset_decimals.insert(asset_id, decimals);
+        log(PaymentAssetSetEvent {
+            asset_id,
+            decimals,
+        });
+    }
+
+    /// Gets the decimals of an asset accepted as payment, or None if
+    /// the asset is not accepted. Returns None for the base asset.
+    #[storage(read)]
+    fn payment_asset_decimals(asset_id: ContractId) -> Option<u8> {
+        storage.asset_decimals.get(asset_id)
+    }
+
+    /// Gets all assets other than the base asset that have been accepted
+    /// as payment.
+    #[storage(read)]
+    fn payment_assets() -> Vec<ContractId> {
+        let mut assets = Vec::new();
+        let mut i = 0;
+        let count = storage.payment_assets.len();
+        while i < count {
+            assets.push(storage.payment_assets.get(i).unwrap());
+            i += 1;
+        }
+        assets
+    }
+
+    /// Sets the gas oracle for a given asset and domain. The oracle's exchange
+    /// rates must be relative to `asset_id`. Only callable by the owner.
+    #[storage(read, write)]
+    fn set_asset_gas_oracle(asset_id: ContractId, domain: u32, gas_oracle: b256) {
+        only_owner();
+        require(asset_id != BASE_ASSET_ID, "cannot configure base asset");
+
+        storage.asset_gas_oracles.insert((asset_id, domain), gas_oracle);
+        log(AssetGasOracleSetEvent {
+            asset_id,
+            domain,
+            gas_oracle,
+        });
+    }
+
+    /// Gets the gas oracle for a given asset and domain.
+    #[storage(read)]
+    fn asset_gas_oracle(asset_id: ContractId, domain: u32) -> Option<b256> {
+        storage.asset_gas_oracles.get((asset_id, domain))
+    }
+
+    /// Quotes the required interchain gas payment to be paid in `asset_id`.
+    /// Reverts if the asset is not accepted or no gas oracle is set.
+    ///
+    /// ### Arguments
+    ///
+    /// * `asset_id` - The asset to pay in.
+    /// * `destination_domain` - The destination domain of the message.
+    /// * `gas_amount` - The amount of destination gas to pay for.
+    #[storage(read)]
+    fn quote_gas_payment_in(
+        asset_id: ContractId,
+        destination_domain: u32,
+        gas_amount: u64,
+    ) -> u64 {
+        quote_gas_payment_in(asset_id, destination_domain, gas_amount)
+    }
+}
+
+impl GasPaymentLedger for Contract {
+    /// Gets the total amount of destination gas paid for a message,
+    /// across all payments.
+    #[storage(read)]
+    fn gas_paid(message_id: b256) -> u64 {
+        storage.gas_paid.get(message_id).unwrap_or(0)
+    }
+}
+
+/// Adds `gas_amount` to the total gas paid for a message in storage.
+/// Reverts if the total gas paid for the message would overflow.
+#[storage(read, write)]
+fn record_gas_paid(message_id: b256, gas_amount: u64) {
+    let gas_paid = storage.gas_paid.get(message_id).unwrap_or(0);
+    require(gas_amount <= u64::max() - gas_paid, "gas paid overflow");
+    storage.gas_paid.insert(message_id, gas_paid + gas_amount);
+}
+
+/// Gets the current beneficiary.
+#[storage(read)]
+fn beneficiary() -> Identity {
+    storage.beneficiary.unwrap_or(INITIAL_BENEFICIARY)
+}
+
+/// Sends the contract's entire balance of `asset_id` to the beneficiary.
+/// Reverts if the beneficiary is the zero address or contract ID.
+#[storage(read, write)]
+fn claim_asset(asset_id: ContractId) {
+    send_claim(asset_id, this_balance(asset_id), beneficiary());
+}
+
+/// Sends `amount` of `asset_id` to `recipient`, adding it to the total claimed.
+/// Reverts if the recipient is the zero address or contract ID, if the
+/// contract's balance of the asset is insufficient, or if the total claimed
+/// would overflow.
+#[storage(read, write)]
+fn send_claim(asset_id: ContractId, amount: u64, recipient: Identity) {
+    require(!is_zero_identity(recipient), "zero beneficiary");
+    require(amount <= this_balance(asset_id), "insufficient balance");
+
+    transfer(amount, asset_id, recipient);
+
+    let total_claimed = storage.total_claimed.get(asset_id).unwrap_or(0);
+    require(amount <= u64::max() - total_claimed, "total claimed overflow");
+    storage.total_claimed.insert(asset_id, total_claimed + amount);
+
+    log(ClaimEvent {
+        beneficiary: recipient,
+        asset_id,
+        amount,
+    });
+}
+
+/// Returns true if the identity is the zero address or contract ID.
+fn is_zero_identity(identity: Identity) -> bool {
+    match identity {
+        Identity::Address(address) => address.value == ZERO_B256,
+        Identity::ContractId(contract_id) => contract_id.value == ZERO_B256,
+    }
+}
+
 /// Gets the exchange rate and gas price for a given domain using the
 /// configured gas oracle.
 /// Reverts if no gas oracle is set.
@@ -197,23 +407,42 @@ fn get_remote_gas_data(destination_domain: u32) -> RemoteGasData {
 /// Reverts if no gas oracle is set.
 #[storage(read)]
 fn quote_gas_payment(destination_domain: u32, gas_amount: u64) -> u64 {
-    // Get the gas data for the destination domain.
+    quote_gas_payment_in(BASE_ASSET_ID, destination_domain, gas_amount)
+}
+
+/// Quotes the required interchain gas payment to be paid in `asset_id`.
+/// Reverts if the asset is not accepted as payment or no gas oracle is set.
+#[storage(read)]
+fn quote_gas_payment_in(
+    asset_id: ContractId,
+    destination_domain: u32,
+    gas_amount: u64,
+) -> u64 {
+    // Get the gas data for the destination domain, relative to the asset.
+    let (remote_gas_data, asset_decimals) = if asset_id == BASE_ASSET_ID {
+        (get_remote_gas_data(destination_domain), BASE_ASSET_DECIMALS)
+    } else {
+        let asset_decimals = storage.asset_decimals.get(asset_id).expect("unsupported payment asset");
+        let gas_oracle_id = storage.asset_gas_oracles.get((asset_id, destination_domain)).expect("no gas oracle set for asset and destination domain");
+        let gas_oracle = abi(GasOracle, gas_oracle_id);
+        (gas_oracle.get_remote_gas_data(destination_domain), asset_decimals)
+    };
     let RemoteGasData {
         token_exchange_rate,
         gas_price,
         token_decimals,
-    } = get_remote_gas_data(destination_domain);
+    } = remote_gas_data;
 
     // All arithmetic is done using U256 to avoid overflows.
 
     // The total cost quoted in destination chain's native token.
     let destination_gas_cost = U256::from((0, 0, 0, gas_amount)) * U256::from(gas_price);
 
-    // Convert to the local native token.
+    // Convert to the payment asset.
     let origin_cost = (destination_gas_cost * U256::from(token_exchange_rate)) / U256::from((0, 0, 0, TOKEN_EXCHANGE_RATE_SCALE));
 
-    // Convert from the remote token's decimals to the local token's decimals.
-    let origin_cost = convert_decimals(origin_cost, token_decimals, BASE_ASSET_DECIMALS);
+    // Convert from the remote token's decimals to the payment asset's decimals.
+    let origin_cost = convert_decimals(origin_cost, token_decimals, asset_decimals);
 
     origin_cost.as_u64().expect("quote_gas_payment overflow")
 }
@@ -2,10 +2,11 @@ use std::str::FromStr;
 
 use fuels::{
     prelude::*,
+    tx::{ContractId, Salt},
     types::{Bits256, Identity},
 };
 
-use test_utils::{funded_wallet_with_private_key, get_revert_reason, get_revert_string};
+use test_utils::{decode_revert_reason, funded_wallet_with_private_key, RevertReason};
 
 // Load abi from json
 abigen!(Contract(
@@ -47,6 +48,34 @@ mod gas_oracle {
     }
 }
 
+mod unpacked_gas_oracle {
+    use fuels::prelude::abigen;
+
+    // Load abi from json
+    abigen!(Contract(
+        name = "UnpackedStorageGasOracle",
+        abi = "contracts/igp/storage-gas-oracle-unpacked-test/out/debug/storage-gas-oracle-unpacked-test-abi.json"
+    ));
+
+    impl From<u64> for U128 {
+        fn from(value: u64) -> Self {
+            Self {
+                upper: 0,
+                lower: value,
+            }
+        }
+    }
+
+    impl From<u128> for U128 {
+        fn from(value: u128) -> Self {
+            Self {
+                upper: (value >> 64) as u64,
+                lower: (value & u128::from(u64::MAX)) as u64,
+            }
+        }
+    }
+}
+
 // The generated gas_oracle U128 is a different type than the generated
 // IGP's U128
 impl From<gas_oracle::U128> for U128 {
@@ -113,17 +142,10 @@ async fn get_contract_instances() -> (
     .await;
     let wallet = wallets.pop().unwrap();
 
-    let igp_id = Contract::deploy(
-        "./out/debug/interchain-gas-paymaster.bin",
-        &wallet,
-        DeployConfiguration::default().set_storage_configuration(StorageConfiguration::new(
-            "./out/debug/interchain-gas-paymaster-storage_slots.json".to_string(),
-            vec![],
-        )),
-    )
-    .await
-    .unwrap();
-    let igp = InterchainGasPaymaster::new(igp_id, wallet.clone());
+    let igp_configurables = InterchainGasPaymasterConfigurables::new().set_INITIAL_BENEFICIARY(
+        Identity::Address(Address::from_str(INITIAL_BENEFICIARY_ADDRESS).unwrap()),
+    );
+    let igp = deploy_igp(&wallet, igp_configurables, [0u8; 32]).await;
 
     let owner_identity = Identity::Address(wallet.address().into());
 
@@ -164,6 +186,27 @@ async fn get_contract_instances() -> (
     (igp, storage_gas_oracle)
 }
 
+async fn deploy_igp(
+    wallet: &WalletUnlocked,
+    configurables: InterchainGasPaymasterConfigurables,
+    salt: [u8; 32],
+) -> InterchainGasPaymaster<WalletUnlocked> {
+    let igp_id = Contract::deploy(
+        "./out/debug/interchain-gas-paymaster.bin",
+        wallet,
+        DeployConfiguration::default()
+            .set_storage_configuration(StorageConfiguration::new(
+                "./out/debug/interchain-gas-paymaster-storage_slots.json".to_string(),
+                vec![],
+            ))
+            .set_configurables(configurables)
+            .set_salt(Salt::from(salt)),
+    )
+    .await
+    .unwrap();
+    InterchainGasPaymaster::new(igp_id, wallet.clone())
+}
+
 async fn set_remote_gas_data(
     oracle: &StorageGasOracle<WalletUnlocked>,
     remote_gas_data_config: RemoteGasDataConfig,
@@ -186,12 +229,43 @@ async fn get_balance(
 async fn get_contract_balance(
     provider: &Provider,
     contract_id: &Bech32ContractId,
+) -> std::result::Result<u64, ProviderError> {
+    get_contract_asset_balance(provider, contract_id, AssetId::BASE).await
+}
+
+async fn get_contract_asset_balance(
+    provider: &Provider,
+    contract_id: &Bech32ContractId,
+    asset_id: AssetId,
 ) -> std::result::Result<u64, ProviderError> {
     provider
-        .get_contract_asset_balance(contract_id, AssetId::BASE)
+        .get_contract_asset_balance(contract_id, asset_id)
         .await
 }
 
+// Accepts the non-base asset as payment with the given decimals, using
+// the storage gas oracle for the test destination domain.
+async fn set_up_non_base_asset(
+    igp: &InterchainGasPaymaster<WalletUnlocked>,
+    oracle: &StorageGasOracle<WalletUnlocked>,
+    decimals: u8,
+) -> Result<()> {
+    let asset_id = ContractId::from(TEST_NON_BASE_ASSET_ID);
+    igp.methods()
+        .set_payment_asset(asset_id, decimals)
+        .call()
+        .await?;
+    igp.methods()
+        .set_asset_gas_oracle(
+            asset_id,
+            TEST_DESTINATION_DOMAIN,
+            Bits256(oracle.contract_id().hash().into()),
+        )
+        .call()
+        .await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_initial_beneficiary() {
     let (igp, _) = get_contract_instances().await;
@@ -341,13 +415,110 @@ async fn test_pay_for_gas_reverts_if_insufficient_payment() {
 
     assert!(call.is_err());
     assert_eq!(
-        get_revert_string(call.err().unwrap()),
-        "insufficient interchain gas payment"
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("insufficient interchain gas payment")
+    );
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_reverts_if_zero_gas_amount() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE.into(),
+                gas_price: 1u64.into(),
+                token_decimals: BASE_ASSET_DECIMALS,
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let refund_address = Address::from_str(TEST_REFUND_ADDRESS).unwrap();
+
+    let call = igp
+        .methods()
+        .pay_for_gas(
+            Bits256::from_hex_str(TEST_MESSAGE_ID).unwrap(),
+            TEST_DESTINATION_DOMAIN,
+            0,
+            Identity::Address(refund_address),
+        )
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("zero gas amount")
+    );
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_reverts_if_gas_paid_overflows() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: 1u64.into(), // remote token is nearly worthless
+                gas_price: 1u64.into(),
+                token_decimals: BASE_ASSET_DECIMALS,
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let message_id = Bits256::from_hex_str(TEST_MESSAGE_ID).unwrap();
+    let refund_address = Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap());
+
+    let quote = igp
+        .methods()
+        .quote_gas_payment(TEST_DESTINATION_DOMAIN, u64::MAX)
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(quote > 0);
+
+    let pay_max_gas = || {
+        igp.methods()
+            .pay_for_gas(
+                message_id,
+                TEST_DESTINATION_DOMAIN,
+                u64::MAX,
+                refund_address.clone(),
+            )
+            .call_params(
+                CallParameters::default()
+                    .set_asset_id(BASE_ASSET_ID)
+                    .set_amount(quote),
+            )
+            .unwrap()
+            .set_contract_ids(&[oracle.contract_id().clone()])
+    };
+
+    pay_max_gas().call().await.unwrap();
+
+    let call = pay_max_gas().call().await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("gas paid overflow")
     );
 }
 
 #[tokio::test]
-async fn test_pay_for_gas_reverts_if_not_base_asset() {
+async fn test_pay_for_gas_reverts_if_unsupported_asset() {
     let (igp, oracle) = get_contract_instances().await;
 
     set_remote_gas_data(
@@ -395,11 +566,196 @@ async fn test_pay_for_gas_reverts_if_not_base_asset() {
 
     assert!(call.is_err());
     assert_eq!(
-        get_revert_string(call.err().unwrap()),
-        "interchain gas payment must be in base asset"
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("unsupported payment asset")
+    );
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_in_non_base_asset() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    // The non-base asset has 6 decimals
+    set_up_non_base_asset(&igp, &oracle, 6).await.unwrap();
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE.into(), // 1.0 exchange rate
+                gas_price: 150u64.into(),                              // 150 gas price
+                token_decimals: BASE_ASSET_DECIMALS,                   // 9 decimals
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let wallet = igp.account();
+    let provider = wallet.provider().unwrap();
+
+    let asset_id = AssetId::new(TEST_NON_BASE_ASSET_ID);
+    let refund_address = Address::from_str(TEST_REFUND_ADDRESS).unwrap();
+
+    let igp_balance_before = get_contract_asset_balance(&provider, igp.contract_id(), asset_id)
+        .await
+        .unwrap();
+    let refund_address_balance_before = provider
+        .get_asset_balance(&refund_address.into(), asset_id)
+        .await
+        .unwrap();
+
+    let quote = igp
+        .methods()
+        .quote_gas_payment_in(
+            ContractId::from(TEST_NON_BASE_ASSET_ID),
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+        )
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    // 300,000 * 150 = 45000000 (0.045 remote tokens w/ 9 decimals)
+    // = 45000 (0.045 local tokens w/ 6 decimals)
+    assert_eq!(quote, 45000u64);
+
+    let overpayment: u64 = 54321u64;
+
+    let call = igp
+        .methods()
+        .pay_for_gas(
+            Bits256::from_hex_str(TEST_MESSAGE_ID).unwrap(),
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            Identity::Address(refund_address),
+        )
+        .call_params(
+            CallParameters::default()
+                .set_asset_id(asset_id)
+                .set_amount(quote + overpayment),
+        )
+        .unwrap()
+        .estimate_tx_dependencies(Some(5))
+        .await
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    // The payment is kept and the overpayment refunded in the non-base asset
+    let igp_balance_after = get_contract_asset_balance(&provider, igp.contract_id(), asset_id)
+        .await
+        .unwrap();
+    let refund_address_balance_after = provider
+        .get_asset_balance(&refund_address.into(), asset_id)
+        .await
+        .unwrap();
+
+    assert_eq!(igp_balance_after - igp_balance_before, quote);
+    assert_eq!(
+        refund_address_balance_after - refund_address_balance_before,
+        overpayment,
+    );
+
+    // A GasPaymentEvent's payment is in the base asset, so only the
+    // AssetGasPaymentEvent is logged
+    let events = call.get_logs_with_type::<GasPaymentEvent>().unwrap();
+    assert_eq!(events, vec![]);
+    let events = call.get_logs_with_type::<AssetGasPaymentEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![AssetGasPaymentEvent {
+            message_id: Bits256::from_hex_str(TEST_MESSAGE_ID).unwrap(),
+            asset_id: ContractId::from(TEST_NON_BASE_ASSET_ID),
+            gas_amount: TEST_GAS_AMOUNT,
+            payment: quote,
+        }]
     );
 }
 
+// ============ gas_paid ============
+
+#[tokio::test]
+async fn test_gas_paid() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    set_up_non_base_asset(&igp, &oracle, 6).await.unwrap();
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE.into(), // 1.0 exchange rate
+                gas_price: 150u64.into(),                              // 150 gas price
+                token_decimals: BASE_ASSET_DECIMALS,                   // 9 decimals
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let message_id = Bits256::from_hex_str(TEST_MESSAGE_ID).unwrap();
+    let refund_address = Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap());
+
+    // Nothing has been paid yet
+    let gas_paid = igp
+        .methods()
+        .gas_paid(message_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_paid, 0);
+
+    // Pay in the base asset, then top up in the non-base asset
+    let payments_to_make = [
+        (BASE_ASSET_ID, TEST_GAS_AMOUNT, 45000000u64),
+        (AssetId::new(TEST_NON_BASE_ASSET_ID), 100000u64, 15000u64),
+    ];
+    for (asset_id, gas_amount, payment) in payments_to_make {
+        igp.methods()
+            .pay_for_gas(
+                message_id,
+                TEST_DESTINATION_DOMAIN,
+                gas_amount,
+                refund_address.clone(),
+            )
+            .call_params(
+                CallParameters::default()
+                    .set_asset_id(asset_id)
+                    .set_amount(payment),
+            )
+            .unwrap()
+            .estimate_tx_dependencies(Some(5))
+            .await
+            .unwrap()
+            .call()
+            .await
+            .unwrap();
+    }
+
+    let gas_paid = igp
+        .methods()
+        .gas_paid(message_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_paid, TEST_GAS_AMOUNT + 100000u64);
+
+    // Payments for other messages are unaffected
+    let gas_paid = igp
+        .methods()
+        .gas_paid(Bits256([0u8; 32]))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_paid, 0);
+}
+
 // ============ quote_gas_payment ============
 
 #[tokio::test]
@@ -553,140 +909,794 @@ async fn test_quote_gas_payment_reverts_if_no_gas_oracle_set() {
 
     assert!(quote.is_err());
     assert_eq!(
-        get_revert_string(quote.err().unwrap()),
-        "no gas oracle set for destination domain"
+        decode_revert_reason(quote.err().unwrap()).unwrap(),
+        RevertReason::require("no gas oracle set for destination domain")
     );
 }
 
-// ============ set_gas_oracle ============
+// ============ quote_gas_payment_in ============
 
 #[tokio::test]
-async fn test_set_gas_oracle() {
+async fn test_quote_gas_payment_in() {
     let (igp, oracle) = get_contract_instances().await;
 
-    let remote_domain = TEST_DESTINATION_DOMAIN + 1;
-    let oracle_contract_id_bits256 = Bits256(oracle.contract_id().hash().into());
+    let asset_id = ContractId::from(TEST_NON_BASE_ASSET_ID);
 
-    // Before it's been set, it should return None
-    let gas_oracle = igp
+    // The asset has 6 decimals and the remote token 9, with a 1:1 exchange rate
+    set_up_non_base_asset(&igp, &oracle, 6).await.unwrap();
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE.into(),
+                gas_price: 150u64.into(),
+                token_decimals: 9u8,
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let quote = igp
         .methods()
-        .gas_oracle(remote_domain)
+        .quote_gas_payment_in(asset_id, TEST_DESTINATION_DOMAIN, TEST_GAS_AMOUNT)
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    // 300,000 * 150 = 45000000 (0.045 remote tokens w/ 9 decimals)
+    // 45000000 * 1e-3 = 45000 (0.045 asset tokens w/ 6 decimals)
+    assert_eq!(quote, 45000u64);
+
+    // The asset and the remote token both have 18 decimals, and the
+    // remote token is half as valuable
+    set_up_non_base_asset(&igp, &oracle, 18).await.unwrap();
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: (TOKEN_EXCHANGE_RATE_SCALE / 2).into(),
+                gas_price: 1000000000u64.into(), // 1 gwei gas price
+                token_decimals: 18u8,
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let quote = igp
+        .methods()
+        .quote_gas_payment_in(asset_id, TEST_DESTINATION_DOMAIN, TEST_GAS_AMOUNT)
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    // 300,000 * 1000000000 = 300000000000000 (0.0003 remote tokens w/ 18 decimals)
+    // 300000000000000 * 0.5 = 150000000000000 (0.00015 asset tokens w/ 18 decimals)
+    assert_eq!(quote, 150000000000000u64);
+
+    // The asset has 12 decimals and the remote token 4, and the
+    // remote token is 10x more valuable
+    set_up_non_base_asset(&igp, &oracle, 12).await.unwrap();
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: (10 * TOKEN_EXCHANGE_RATE_SCALE).into(),
+                gas_price: 10u64.into(),
+                token_decimals: 4u8,
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let quote = igp
+        .methods()
+        .quote_gas_payment_in(asset_id, TEST_DESTINATION_DOMAIN, TEST_GAS_AMOUNT)
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    // 300,000 * 10 = 3000000 (300.0000 remote tokens w/ 4 decimals)
+    // 3000000 * 10 * 1e8 = 3000000000000000 (3000 asset tokens w/ 12 decimals)
+    assert_eq!(quote, 3000000000000000u64);
+}
+
+#[tokio::test]
+async fn test_quote_gas_payment_in_base_asset() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    set_remote_gas_data(
+        &oracle,
+        RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: (TOKEN_EXCHANGE_RATE_SCALE / 5).into(),
+                gas_price: 150u64.into(),
+                token_decimals: BASE_ASSET_DECIMALS,
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let quote = igp
+        .methods()
+        .quote_gas_payment(TEST_DESTINATION_DOMAIN, TEST_GAS_AMOUNT)
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    // Quoting in the base asset is equivalent to `quote_gas_payment`
+    let quote_in = igp
+        .methods()
+        .quote_gas_payment_in(
+            ContractId::from(*BASE_ASSET_ID),
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+        )
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(quote_in, quote);
+}
+
+#[tokio::test]
+async fn test_quote_gas_payment_in_reverts_if_unsupported_asset() {
+    let (igp, _) = get_contract_instances().await;
+
+    let call = igp
+        .methods()
+        .quote_gas_payment_in(
+            ContractId::from(TEST_NON_BASE_ASSET_ID),
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+        )
+        .simulate()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("unsupported payment asset")
+    );
+}
+
+#[tokio::test]
+async fn test_quote_gas_payment_in_reverts_if_no_gas_oracle_set() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    set_up_non_base_asset(&igp, &oracle, 6).await.unwrap();
+
+    let call = igp
+        .methods()
+        .quote_gas_payment_in(
+            ContractId::from(TEST_NON_BASE_ASSET_ID),
+            TEST_DESTINATION_DOMAIN + 1,
+            TEST_GAS_AMOUNT,
+        )
+        .simulate()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("no gas oracle set for asset and destination domain")
+    );
+}
+
+// ============ set_payment_asset ============
+
+#[tokio::test]
+async fn test_set_payment_asset() {
+    let (igp, _) = get_contract_instances().await;
+
+    let asset_id = ContractId::from(TEST_NON_BASE_ASSET_ID);
+
+    let decimals = igp
+        .methods()
+        .payment_asset_decimals(asset_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(decimals, None);
+
+    let call = igp
+        .methods()
+        .set_payment_asset(asset_id, 6)
+        .call()
+        .await
+        .unwrap();
+    let events = call.get_logs_with_type::<PaymentAssetSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![PaymentAssetSetEvent {
+            asset_id,
+            decimals: 6,
+        }]
+    );
+
+    // Setting the asset again only updates its decimals
+    igp.methods()
+        .set_payment_asset(asset_id, 18)
+        .call()
+        .await
+        .unwrap();
+
+    let decimals = igp
+        .methods()
+        .payment_asset_decimals(asset_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(decimals, Some(18));
+
+    let assets = igp
+        .methods()
+        .payment_assets()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(assets, vec![asset_id]);
+}
+
+#[tokio::test]
+async fn test_set_payment_asset_reverts_if_base_asset() {
+    let (igp, _) = get_contract_instances().await;
+
+    let call = igp
+        .methods()
+        .set_payment_asset(ContractId::from(*BASE_ASSET_ID), 6)
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("cannot configure base asset")
+    );
+}
+
+#[tokio::test]
+async fn test_set_payment_asset_reverts_if_not_owner() {
+    let (igp, _) = get_contract_instances().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&igp.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = igp
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_payment_asset(ContractId::from(TEST_NON_BASE_ASSET_ID), 6)
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ set_asset_gas_oracle ============
+
+#[tokio::test]
+async fn test_set_asset_gas_oracle() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    let asset_id = ContractId::from(TEST_NON_BASE_ASSET_ID);
+    let oracle_contract_id_bits256 = Bits256(oracle.contract_id().hash().into());
+
+    let gas_oracle = igp
+        .methods()
+        .asset_gas_oracle(asset_id, TEST_DESTINATION_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_oracle, None);
+
+    let call = igp
+        .methods()
+        .set_asset_gas_oracle(
+            asset_id,
+            TEST_DESTINATION_DOMAIN,
+            oracle_contract_id_bits256,
+        )
         .call()
         .await
-        .unwrap()
-        .value;
-    assert_eq!(gas_oracle, None);
+        .unwrap();
+    let events = call.get_logs_with_type::<AssetGasOracleSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![AssetGasOracleSetEvent {
+            asset_id,
+            domain: TEST_DESTINATION_DOMAIN,
+            gas_oracle: oracle_contract_id_bits256,
+        }]
+    );
+
+    let gas_oracle = igp
+        .methods()
+        .asset_gas_oracle(asset_id, TEST_DESTINATION_DOMAIN)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_oracle, Some(oracle_contract_id_bits256));
+}
+
+#[tokio::test]
+async fn test_set_asset_gas_oracle_reverts_if_not_owner() {
+    let (igp, oracle) = get_contract_instances().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&igp.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = igp
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_asset_gas_oracle(
+            ContractId::from(TEST_NON_BASE_ASSET_ID),
+            TEST_DESTINATION_DOMAIN,
+            Bits256(oracle.contract_id().hash().into()),
+        )
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ set_gas_oracle ============
+
+#[tokio::test]
+async fn test_set_gas_oracle() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    let remote_domain = TEST_DESTINATION_DOMAIN + 1;
+    let oracle_contract_id_bits256 = Bits256(oracle.contract_id().hash().into());
+
+    // Before it's been set, it should return None
+    let gas_oracle = igp
+        .methods()
+        .gas_oracle(remote_domain)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_oracle, None);
+
+    // Now set the gas oracle
+    let call = igp
+        .methods()
+        .set_gas_oracle(remote_domain, oracle_contract_id_bits256)
+        .call()
+        .await
+        .unwrap();
+    let events = call.get_logs_with_type::<GasOracleSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![GasOracleSetEvent {
+            domain: remote_domain,
+            gas_oracle: oracle_contract_id_bits256,
+        }]
+    );
+
+    // Ensure it's actually been set
+    let gas_oracle = igp
+        .methods()
+        .gas_oracle(remote_domain)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_oracle, Some(oracle_contract_id_bits256));
+}
+
+#[tokio::test]
+async fn test_set_gas_oracle_reverts_if_not_owner() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    let remote_domain = TEST_DESTINATION_DOMAIN + 1;
+    let oracle_contract_id_bits256 = Bits256(oracle.contract_id().hash().into());
+
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = igp
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_gas_oracle(remote_domain, oracle_contract_id_bits256)
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ set_beneficiary ============
+
+#[tokio::test]
+async fn test_set_beneficiary() {
+    let (igp, _) = get_contract_instances().await;
+
+    let new_beneficiary = Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap());
+
+    let call = igp
+        .methods()
+        .set_beneficiary(new_beneficiary.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let events = call.get_logs_with_type::<BeneficiarySetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![BeneficiarySetEvent {
+            beneficiary: new_beneficiary.clone(),
+        }]
+    );
+
+    // Before it's been set, it should return None
+    let beneficiary = igp.methods().beneficiary().call().await.unwrap().value;
+    assert_eq!(beneficiary, new_beneficiary);
+}
+
+#[tokio::test]
+async fn test_set_beneficiary_reverts_if_not_owner() {
+    let (igp, _) = get_contract_instances().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&igp.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let new_beneficiary = Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap());
+
+    let call = igp
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_beneficiary(new_beneficiary)
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+// ============ claim ============
+
+#[tokio::test]
+async fn test_claim() {
+    let (igp, _) = get_contract_instances().await;
+
+    let amount = 12345677u64;
+
+    let wallet = igp.account();
+    // Send some tokens to the contract
+    let (_, _) = wallet
+        .force_transfer_to_contract(
+            igp.contract_id(),
+            amount,
+            BASE_ASSET_ID,
+            TxParameters::default(),
+        )
+        .await
+        .unwrap();
+
+    let provider = wallet.provider().unwrap();
+
+    let beneficiary = Address::from_str(INITIAL_BENEFICIARY_ADDRESS).unwrap();
+
+    let beneficiary_balance_before = get_balance(provider, &beneficiary.into()).await.unwrap();
+    let igp_balance_before = get_contract_balance(provider, igp.contract_id())
+        .await
+        .unwrap();
+
+    // Claim the tokens
+    let call = igp
+        .methods()
+        .claim()
+        .estimate_tx_dependencies(Some(5))
+        .await
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    let events = call.get_logs_with_type::<ClaimEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![ClaimEvent {
+            beneficiary: Identity::Address(beneficiary),
+            asset_id: ContractId::from(*BASE_ASSET_ID),
+            amount: amount,
+        }]
+    );
+
+    let beneficiary_balance_after = get_balance(provider, &beneficiary.into()).await.unwrap();
+    let igp_balance_after = get_contract_balance(provider, igp.contract_id())
+        .await
+        .unwrap();
+
+    assert_eq!(igp_balance_before - igp_balance_after, amount);
+    assert_eq!(
+        beneficiary_balance_after - beneficiary_balance_before,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn test_claim_all_payment_assets() {
+    let (igp, oracle) = get_contract_instances().await;
+
+    set_up_non_base_asset(&igp, &oracle, 6).await.unwrap();
+
+    let base_amount = 12345677u64;
+    let non_base_amount = 7654321u64;
+    let non_base_asset_id = AssetId::new(TEST_NON_BASE_ASSET_ID);
+
+    let wallet = igp.account();
+    // Send some of each asset to the contract
+    for (amount, asset_id) in [
+        (base_amount, BASE_ASSET_ID),
+        (non_base_amount, non_base_asset_id),
+    ] {
+        wallet
+            .force_transfer_to_contract(
+                igp.contract_id(),
+                amount,
+                asset_id,
+                TxParameters::default(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let provider = wallet.provider().unwrap();
+
+    let beneficiary = Address::from_str(INITIAL_BENEFICIARY_ADDRESS).unwrap();
+
+    let beneficiary_non_base_balance_before = provider
+        .get_asset_balance(&beneficiary.into(), non_base_asset_id)
+        .await
+        .unwrap();
 
-    // Now set the gas oracle
     let call = igp
         .methods()
-        .set_gas_oracle(remote_domain, oracle_contract_id_bits256)
+        .claim()
+        .estimate_tx_dependencies(Some(5))
+        .await
+        .unwrap()
         .call()
         .await
         .unwrap();
-    let events = call.get_logs_with_type::<GasOracleSetEvent>().unwrap();
+
+    let events = call.get_logs_with_type::<ClaimEvent>().unwrap();
     assert_eq!(
         events,
-        vec![GasOracleSetEvent {
-            domain: remote_domain,
-            gas_oracle: oracle_contract_id_bits256,
-        }]
+        vec![
+            ClaimEvent {
+                beneficiary: Identity::Address(beneficiary),
+                asset_id: ContractId::from(*BASE_ASSET_ID),
+                amount: base_amount,
+            },
+            ClaimEvent {
+                beneficiary: Identity::Address(beneficiary),
+                asset_id: ContractId::from(TEST_NON_BASE_ASSET_ID),
+                amount: non_base_amount,
+            },
+        ]
     );
 
-    // Ensure it's actually been set
-    let gas_oracle = igp
-        .methods()
-        .gas_oracle(remote_domain)
-        .call()
+    let beneficiary_non_base_balance_after = provider
+        .get_asset_balance(&beneficiary.into(), non_base_asset_id)
         .await
-        .unwrap()
-        .value;
-    assert_eq!(gas_oracle, Some(oracle_contract_id_bits256));
+        .unwrap();
+    assert_eq!(
+        beneficiary_non_base_balance_after - beneficiary_non_base_balance_before,
+        non_base_amount
+    );
+
+    let igp_non_base_balance =
+        get_contract_asset_balance(provider, igp.contract_id(), non_base_asset_id)
+            .await
+            .unwrap();
+    assert_eq!(igp_non_base_balance, 0);
 }
 
 #[tokio::test]
-async fn test_set_gas_oracle_reverts_if_not_owner() {
-    let (igp, oracle) = get_contract_instances().await;
+async fn test_claim_reverts_if_zero_beneficiary() {
+    let (igp, _) = get_contract_instances().await;
+    let wallet = igp.account();
 
-    let remote_domain = TEST_DESTINATION_DOMAIN + 1;
-    let oracle_contract_id_bits256 = Bits256(oracle.contract_id().hash().into());
+    // Deploy an IGP without configuring the initial beneficiary
+    let misconfigured_igp = deploy_igp(
+        &wallet,
+        InterchainGasPaymasterConfigurables::new(),
+        [1u8; 32],
+    )
+    .await;
 
-    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+    let beneficiary = misconfigured_igp
+        .methods()
+        .beneficiary()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(beneficiary, Identity::Address(Address::zeroed()));
+
+    let amount = 12345677u64;
+    wallet
+        .force_transfer_to_contract(
+            misconfigured_igp.contract_id(),
+            amount,
+            BASE_ASSET_ID,
+            TxParameters::default(),
+        )
         .await
         .unwrap();
 
-    let call = igp
-        .with_account(non_owner_wallet)
-        .unwrap()
+    let call = misconfigured_igp
         .methods()
-        .set_gas_oracle(remote_domain, oracle_contract_id_bits256)
+        .claim()
+        .append_variable_outputs(1)
         .call()
         .await;
-
     assert!(call.is_err());
-    assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("zero beneficiary")
+    );
+
+    // The funds remain in the contract
+    let provider = wallet.provider().unwrap();
+    let igp_balance = get_contract_balance(provider, misconfigured_igp.contract_id())
+        .await
+        .unwrap();
+    assert_eq!(igp_balance, amount);
 }
 
-// ============ set_beneficiary ============
+// ============ claim_amount ============
 
 #[tokio::test]
-async fn test_set_beneficiary() {
+async fn test_claim_amount() {
     let (igp, _) = get_contract_instances().await;
 
-    let new_beneficiary = Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap());
+    let amount = 12345677u64;
+    let claim_amount = 10000000u64;
+    let base_asset_id = ContractId::from(*BASE_ASSET_ID);
 
-    let call = igp
-        .methods()
-        .set_beneficiary(new_beneficiary.clone())
-        .call()
+    let wallet = igp.account();
+    wallet
+        .force_transfer_to_contract(
+            igp.contract_id(),
+            amount,
+            BASE_ASSET_ID,
+            TxParameters::default(),
+        )
         .await
         .unwrap();
 
-    let events = call.get_logs_with_type::<BeneficiarySetEvent>().unwrap();
+    let provider = wallet.provider().unwrap();
+    let beneficiary = Address::from_str(INITIAL_BENEFICIARY_ADDRESS).unwrap();
+    let beneficiary_balance_before = get_balance(provider, &beneficiary.into()).await.unwrap();
+
+    // Claim part of the balance, twice
+    for _ in 0..2 {
+        let claim_amount = claim_amount / 2;
+        let call = igp
+            .methods()
+            .claim_amount(base_asset_id, claim_amount)
+            .append_variable_outputs(1)
+            .call()
+            .await
+            .unwrap();
+
+        let events = call.get_logs_with_type::<ClaimEvent>().unwrap();
+        assert_eq!(
+            events,
+            vec![ClaimEvent {
+                beneficiary: Identity::Address(beneficiary),
+                asset_id: base_asset_id,
+                amount: claim_amount,
+            }]
+        );
+    }
+
+    let beneficiary_balance_after = get_balance(provider, &beneficiary.into()).await.unwrap();
+    let igp_balance_after = get_contract_balance(provider, igp.contract_id())
+        .await
+        .unwrap();
     assert_eq!(
-        events,
-        vec![BeneficiarySetEvent {
-            beneficiary: new_beneficiary.clone(),
-        }]
+        beneficiary_balance_after - beneficiary_balance_before,
+        claim_amount
     );
+    assert_eq!(igp_balance_after, amount - claim_amount);
 
-    // Before it's been set, it should return None
-    let beneficiary = igp.methods().beneficiary().call().await.unwrap().value;
-    assert_eq!(beneficiary, new_beneficiary);
+    let total_claimed = igp
+        .methods()
+        .total_claimed(base_asset_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(total_claimed, claim_amount);
 }
 
 #[tokio::test]
-async fn test_set_beneficiary_reverts_if_not_owner() {
+async fn test_claim_amount_reverts_if_insufficient_balance() {
     let (igp, _) = get_contract_instances().await;
-    let non_owner_wallet = funded_wallet_with_private_key(&igp.account(), NON_OWNER_PRIVATE_KEY)
+
+    let amount = 12345677u64;
+
+    let wallet = igp.account();
+    wallet
+        .force_transfer_to_contract(
+            igp.contract_id(),
+            amount,
+            BASE_ASSET_ID,
+            TxParameters::default(),
+        )
         .await
         .unwrap();
 
-    let new_beneficiary = Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap());
-
     let call = igp
-        .with_account(non_owner_wallet)
-        .unwrap()
         .methods()
-        .set_beneficiary(new_beneficiary)
+        .claim_amount(ContractId::from(*BASE_ASSET_ID), amount + 1)
+        .append_variable_outputs(1)
         .call()
         .await;
 
     assert!(call.is_err());
-    assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("insufficient balance")
+    );
 }
 
-// ============ claim ============
+// ============ claim_to ============
 
 #[tokio::test]
-async fn test_claim() {
+async fn test_claim_to() {
     let (igp, _) = get_contract_instances().await;
 
     let amount = 12345677u64;
+    let base_asset_id = ContractId::from(*BASE_ASSET_ID);
 
     let wallet = igp.account();
-    // Send some tokens to the contract
-    let (_, _) = wallet
+    wallet
         .force_transfer_to_contract(
             igp.contract_id(),
             amount,
@@ -697,21 +1707,13 @@ async fn test_claim() {
         .unwrap();
 
     let provider = wallet.provider().unwrap();
+    let recipient = Address::from_str(TEST_REFUND_ADDRESS).unwrap();
+    let recipient_balance_before = get_balance(provider, &recipient.into()).await.unwrap();
 
-    let beneficiary = Address::from_str(INITIAL_BENEFICIARY_ADDRESS).unwrap();
-
-    let beneficiary_balance_before = get_balance(provider, &beneficiary.into()).await.unwrap();
-    let igp_balance_before = get_contract_balance(provider, igp.contract_id())
-        .await
-        .unwrap();
-
-    // Claim the tokens
     let call = igp
         .methods()
-        .claim()
-        .estimate_tx_dependencies(Some(5))
-        .await
-        .unwrap()
+        .claim_to(base_asset_id, amount, Identity::Address(recipient))
+        .append_variable_outputs(1)
         .call()
         .await
         .unwrap();
@@ -720,20 +1722,48 @@ async fn test_claim() {
     assert_eq!(
         events,
         vec![ClaimEvent {
-            beneficiary: Identity::Address(beneficiary),
-            amount: amount,
+            beneficiary: Identity::Address(recipient),
+            asset_id: base_asset_id,
+            amount,
         }]
     );
 
-    let beneficiary_balance_after = get_balance(provider, &beneficiary.into()).await.unwrap();
-    let igp_balance_after = get_contract_balance(provider, igp.contract_id())
+    let recipient_balance_after = get_balance(provider, &recipient.into()).await.unwrap();
+    assert_eq!(recipient_balance_after - recipient_balance_before, amount);
+
+    let total_claimed = igp
+        .methods()
+        .total_claimed(base_asset_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(total_claimed, amount);
+}
+
+#[tokio::test]
+async fn test_claim_to_reverts_if_not_owner() {
+    let (igp, _) = get_contract_instances().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&igp.account(), NON_OWNER_PRIVATE_KEY)
         .await
         .unwrap();
 
-    assert_eq!(igp_balance_before - igp_balance_after, amount);
+    let call = igp
+        .with_account(non_owner_wallet.clone())
+        .unwrap()
+        .methods()
+        .claim_to(
+            ContractId::from(*BASE_ASSET_ID),
+            0,
+            Identity::Address(non_owner_wallet.address().into()),
+        )
+        .call()
+        .await;
+
+    assert!(call.is_err());
     assert_eq!(
-        beneficiary_balance_after - beneficiary_balance_before,
-        amount
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
     );
 }
 
@@ -798,7 +1828,109 @@ async fn test_get_remote_gas_data_reverts_if_no_gas_oracle_set() {
 
     assert!(call.is_err());
     assert_eq!(
-        get_revert_string(call.err().unwrap()),
-        "no gas oracle set for destination domain"
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("no gas oracle set for destination domain")
+    );
+}
+
+// ============ gas usage ============
+
+// The storage gas oracle packs remote gas data into a single storage slot.
+// Setting and quoting remote gas data must use less gas than with a baseline
+// oracle that stores it unpacked.
+#[tokio::test]
+async fn test_gas_usage_of_packed_remote_gas_data() {
+    let (igp, oracle) = get_contract_instances().await;
+    let wallet = igp.account();
+    let owner_identity = Identity::Address(wallet.address().into());
+
+    let unpacked_oracle_id = Contract::deploy(
+        "../storage-gas-oracle-unpacked-test/out/debug/storage-gas-oracle-unpacked-test.bin",
+        &wallet,
+        DeployConfiguration::default().set_storage_configuration(StorageConfiguration::new(
+            "../storage-gas-oracle-unpacked-test/out/debug/storage-gas-oracle-unpacked-test-storage_slots.json".to_string(),
+            vec![],
+        )),
+    )
+    .await
+    .unwrap();
+    let unpacked_oracle = unpacked_gas_oracle::UnpackedStorageGasOracle::new(
+        unpacked_oracle_id.clone(),
+        wallet.clone(),
     );
+    unpacked_oracle
+        .methods()
+        .set_ownership(owner_identity.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // An otherwise identical IGP that quotes using the unpacked oracle
+    let unpacked_igp = deploy_igp(
+        &wallet,
+        InterchainGasPaymasterConfigurables::new(),
+        [1u8; 32],
+    )
+    .await;
+    unpacked_igp
+        .methods()
+        .set_ownership(owner_identity)
+        .call()
+        .await
+        .unwrap();
+    unpacked_igp
+        .methods()
+        .set_gas_oracle(
+            TEST_DESTINATION_DOMAIN,
+            Bits256(unpacked_oracle_id.hash().into()),
+        )
+        .call()
+        .await
+        .unwrap();
+
+    let packed_set_call = oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: gas_oracle::RemoteGasData {
+                token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE.into(),
+                gas_price: 1u64.into(),
+                token_decimals: BASE_ASSET_DECIMALS,
+            },
+        }])
+        .call()
+        .await
+        .unwrap();
+    let unpacked_set_call = unpacked_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![unpacked_gas_oracle::RemoteGasDataConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            remote_gas_data: unpacked_gas_oracle::RemoteGasData {
+                token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE.into(),
+                gas_price: 1u64.into(),
+                token_decimals: BASE_ASSET_DECIMALS,
+            },
+        }])
+        .call()
+        .await
+        .unwrap();
+    assert!(packed_set_call.gas_used < unpacked_set_call.gas_used);
+
+    let packed_quote_call = igp
+        .methods()
+        .quote_gas_payment(TEST_DESTINATION_DOMAIN, TEST_GAS_AMOUNT)
+        .set_contract_ids(&[oracle.contract_id().clone()])
+        .call()
+        .await
+        .unwrap();
+    let unpacked_quote_call = unpacked_igp
+        .methods()
+        .quote_gas_payment(TEST_DESTINATION_DOMAIN, TEST_GAS_AMOUNT)
+        .set_contract_ids(&[unpacked_oracle_id])
+        .call()
+        .await
+        .unwrap();
+    // Both quote the same payment
+    assert_eq!(packed_quote_call.value, unpacked_quote_call.value);
+    assert!(packed_quote_call.gas_used < unpacked_quote_call.gas_used);
 }
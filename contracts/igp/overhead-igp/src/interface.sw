@@ -1,9 +1,17 @@
 library;
 
 /// A configuration for a domain and its gas overhead.
+/// The gas amount forwarded to the inner IGP is
+/// `max((gas_amount + gas_overhead) * gas_multiplier_bps / 10000, min_gas)`.
 pub struct GasOverheadConfig {
     domain: u32,
+    /// A flat amount of gas added to the gas amount.
     gas_overhead: u64,
+    /// A multiplier in basis points applied after the overhead is added,
+    /// e.g. 12000 for a 20% buffer. Zero is treated as 10000, i.e. no multiplier.
+    gas_multiplier_bps: u64,
+    /// The minimum gas amount.
+    min_gas: u64,
 }
 
 /// Logged when a destination gas overhead for a domain is set.
@@ -11,6 +19,11 @@ pub struct DestinationGasOverheadSetEvent {
     config: GasOverheadConfig,
 }
 
+/// Logged when the inner IGP is set.
+pub struct InnerIgpSetEvent {
+    inner_igp: b256,
+}
+
 /// An InterchainGasPaymaster that adds configured gas overheads to gas amounts.
 abi OverheadIgp {
     /// Sets the gas overheads for destination domains.
@@ -21,6 +34,23 @@ abi OverheadIgp {
     #[storage(read)]
     fn destination_gas_overhead(domain: u32) -> u64;
 
+    /// Gets the gas multiplier in basis points for a destination domain.
+    #[storage(read)]
+    fn destination_gas_multiplier_bps(domain: u32) -> u64;
+
+    /// Gets the minimum gas amount for a destination domain.
+    #[storage(read)]
+    fn destination_min_gas(domain: u32) -> u64;
+
+    /// Gets the gas amount forwarded to the inner IGP for a destination domain.
+    #[storage(read)]
+    fn destination_gas_amount(domain: u32, gas_amount: u64) -> u64;
+
     /// Gets the inner IGP contract ID.
+    #[storage(read)]
     fn inner_igp() -> b256;
+
+    /// Sets the inner IGP contract ID.
+    #[storage(read, write)]
+    fn set_inner_igp(inner_igp: b256);
 }
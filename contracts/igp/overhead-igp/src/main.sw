@@ -2,30 +2,44 @@ contract;
 
 mod interface;
 
-use std::{call_frames::msg_asset_id, constants::ZERO_B256, context::msg_amount};
+use std::{call_frames::msg_asset_id, constants::ZERO_B256, context::msg_amount, u256::U256};
 
 use hyperlane_interfaces::{igp::InterchainGasPaymaster, ownable::Ownable};
 
 use ownership::{data_structures::State, only_owner, owner, set_ownership, transfer_ownership};
 
-use interface::{DestinationGasOverheadSetEvent, GasOverheadConfig, OverheadIgp};
+use std_lib_extended::result::*;
+
+use interface::{DestinationGasOverheadSetEvent, GasOverheadConfig, InnerIgpSetEvent, OverheadIgp};
+
+/// The scale of basis points. 1e4.
+const BPS_SCALE: u64 = 10_000;
 
 configurable {
-    /// The inner IGP contract ID. Expected to be set at deploy time.
+    /// The inner IGP contract ID until one is set by the owner.
+    /// Expected to be set at deploy time.
     INNER_IGP_ID: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000,
 }
 
 storage {
     /// Destination domain -> gas overhead.
     destination_gas_overheads: StorageMap<u32, u64> = StorageMap {},
+    /// Destination domain -> gas multiplier in basis points.
+    destination_gas_multipliers_bps: StorageMap<u32, u64> = StorageMap {},
+    /// Destination domain -> minimum gas amount.
+    destination_min_gas: StorageMap<u32, u64> = StorageMap {},
+    /// The inner IGP contract ID set by the owner. If None, `INNER_IGP_ID` is used.
+    inner_igp: Option<b256> = Option::None,
 }
 
 /// An IGP that wraps an inner IGP, adding a configured amount of gas to
 /// the `pay_for_gas` and `quote_gas_payment` functions before passing the call
-/// along to the inner IGP.
+/// along to the inner IGP. The gas amount may also be scaled by a multiplier
+/// and raised to a minimum, e.g. to account for L1 data costs.
 /// The intended use is for applications to not need to worry about ISM gas costs themselves.
 impl OverheadIgp for Contract {
-    /// Sets the gas overheads for destination domains.
+    /// Sets the gas overheads, multipliers and minimums for destination domains.
+    /// Reverts if a multiplier is nonzero and less than 1x.
     #[storage(read, write)]
     fn set_destination_gas_overheads(configs: Vec<GasOverheadConfig>) {
         only_owner();
@@ -34,7 +48,11 @@ impl OverheadIgp for Contract {
         let mut i = 0;
         while i < count {
             let config = configs.get(i).unwrap();
+            require(config.gas_multiplier_bps == 0 || config.gas_multiplier_bps >= BPS_SCALE, "gas multiplier below 1x");
+
             storage.destination_gas_overheads.insert(config.domain, config.gas_overhead);
+            storage.destination_gas_multipliers_bps.insert(config.domain, config.gas_multiplier_bps);
+            storage.destination_min_gas.insert(config.domain, config.min_gas);
             log(DestinationGasOverheadSetEvent { config });
 
             i += 1;
@@ -47,15 +65,47 @@ impl OverheadIgp for Contract {
         destination_gas_overhead(domain)
     }
 
+    /// Gets the gas multiplier in basis points for a destination domain,
+    /// or 10000 (i.e. no multiplier) if none is set.
+    #[storage(read)]
+    fn destination_gas_multiplier_bps(domain: u32) -> u64 {
+        destination_gas_multiplier_bps(domain)
+    }
+
+    /// Gets the minimum gas amount for a destination domain, or 0 if none is set.
+    #[storage(read)]
+    fn destination_min_gas(domain: u32) -> u64 {
+        storage.destination_min_gas.get(domain).unwrap_or(0)
+    }
+
+    /// Gets the gas amount forwarded to the inner IGP for a destination domain.
+    /// Reverts if the gas amount overflows.
+    #[storage(read)]
+    fn destination_gas_amount(domain: u32, gas_amount: u64) -> u64 {
+        destination_gas_amount(domain, gas_amount)
+    }
+
     /// Gets the inner IGP contract ID.
+    #[storage(read)]
     fn inner_igp() -> b256 {
-        INNER_IGP_ID
+        inner_igp()
+    }
+
+    /// Sets the inner IGP contract ID. Only callable by the owner.
+    /// Reverts if `inner_igp` is zero.
+    #[storage(read, write)]
+    fn set_inner_igp(inner_igp: b256) {
+        only_owner();
+        require(inner_igp != ZERO_B256, "zero inner IGP");
+
+        storage.inner_igp = Option::Some(inner_igp);
+        log(InnerIgpSetEvent { inner_igp });
     }
 }
 
 impl InterchainGasPaymaster for Contract {
-    /// Forwards along the gas payment to the inner IGP, adding the configured
-    /// gas overhead for the destination domain.
+    /// Forwards along the gas payment to the inner IGP, applying the configured
+    /// gas overhead, multiplier and minimum for the destination domain.
     #[storage(read, write)]
     #[payable]
     fn pay_for_gas(
@@ -64,21 +114,22 @@ impl InterchainGasPaymaster for Contract {
         gas_amount: u64,
         refund_address: Identity,
     ) {
-        let inner_igp = abi(InterchainGasPaymaster, INNER_IGP_ID);
+        let inner_igp = abi(InterchainGasPaymaster, inner_igp());
 
         // Forward along the gas payment to the inner IGP.
         // We intentionally leave the restriction of which asset IDs are valid to the inner IGP.
         inner_igp.pay_for_gas {
             asset_id: msg_asset_id().value,
             coins: msg_amount(),
-        }(message_id, destination_domain, gas_amount + destination_gas_overhead(destination_domain), refund_address);
+        }(message_id, destination_domain, destination_gas_amount(destination_domain, gas_amount), refund_address);
     }
 
-    /// Forwards the call to the inner IGP, adding the configured gas overhead for the destination domain.
+    /// Forwards the call to the inner IGP, applying the configured gas overhead,
+    /// multiplier and minimum for the destination domain.
     #[storage(read)]
     fn quote_gas_payment(destination_domain: u32, gas_amount: u64) -> u64 {
-        let inner_igp = abi(InterchainGasPaymaster, INNER_IGP_ID);
-        inner_igp.quote_gas_payment(destination_domain, gas_amount + destination_gas_overhead(destination_domain))
+        let inner_igp = abi(InterchainGasPaymaster, inner_igp());
+        inner_igp.quote_gas_payment(destination_domain, destination_gas_amount(destination_domain, gas_amount))
     }
 }
 
@@ -104,8 +155,63 @@ impl Ownable for Contract {
     }
 }
 
+/// Gets the current inner IGP contract ID.
+#[storage(read)]
+fn inner_igp() -> b256 {
+    storage.inner_igp.unwrap_or(INNER_IGP_ID)
+}
+
 /// Gets the gas overhead for a domain, or 0 if none is set.
 #[storage(read)]
 fn destination_gas_overhead(domain: u32) -> u64 {
     storage.destination_gas_overheads.get(domain).unwrap_or(0)
 }
+
+/// Gets the gas multiplier in basis points for a domain, or BPS_SCALE if none is set.
+#[storage(read)]
+fn destination_gas_multiplier_bps(domain: u32) -> u64 {
+    match storage.destination_gas_multipliers_bps.get(domain) {
+        Option::Some(0) => BPS_SCALE,
+        Option::Some(multiplier_bps) => multiplier_bps,
+        Option::None => BPS_SCALE,
+    }
+}
+
+/// Gets the gas amount to forward to the inner IGP for a domain.
+#[storage(read)]
+fn destination_gas_amount(domain: u32, gas_amount: u64) -> u64 {
+    apply_gas_config(gas_amount, destination_gas_overhead(domain), destination_gas_multiplier_bps(domain), storage.destination_min_gas.get(domain).unwrap_or(0))
+}
+
+/// Returns `max((gas_amount + gas_overhead) * gas_multiplier_bps / BPS_SCALE, min_gas)`.
+/// Intermediate values are computed as U256 to avoid overflow.
+/// Reverts if the result doesn't fit in a u64.
+fn apply_gas_config(
+    gas_amount: u64,
+    gas_overhead: u64,
+    gas_multiplier_bps: u64,
+    min_gas: u64,
+) -> u64 {
+    let gas = (U256::from((0, 0, 0, gas_amount)) + U256::from((0, 0, 0, gas_overhead))) * U256::from((0, 0, 0, gas_multiplier_bps)) / U256::from((0, 0, 0, BPS_SCALE));
+    let gas = gas.as_u64().expect("gas amount overflow");
+    if gas < min_gas { min_gas } else { gas }
+}
+
+#[test()]
+fn test_apply_gas_config() {
+    // Overhead only.
+    assert(apply_gas_config(300_000, 100_000, BPS_SCALE, 0) == 400_000);
+    // 20% buffer applied after the overhead.
+    assert(apply_gas_config(300_000, 100_000, 12_000, 0) == 480_000);
+    // Minimum gas.
+    assert(apply_gas_config(300_000, 100_000, 12_000, 500_000) == 500_000);
+    // Intermediate values larger than a u64 don't overflow.
+    let max = 18_446_744_073_709_551_615;
+    assert(apply_gas_config(max - 10, 10, BPS_SCALE, 0) == max);
+}
+
+#[test(should_revert)]
+fn test_apply_gas_config_reverts_if_overflow() {
+    let max = 18_446_744_073_709_551_615;
+    let _ = apply_gas_config(max, 0, 10_001, 0);
+}
@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use fuels::{
     prelude::*,
+    tx::Salt,
     types::{Bits256, Identity},
 };
 
@@ -21,7 +22,7 @@ mod test_igp_contract {
 }
 
 use test_igp_contract::TestInterchainGasPaymaster;
-use test_utils::{funded_wallet_with_private_key, get_revert_reason};
+use test_utils::{decode_revert_reason, funded_wallet_with_private_key, RevertReason};
 
 const TEST_DESTINATION_DOMAIN: u32 = 11111;
 const TEST_GAS_AMOUNT: u64 = 300000;
@@ -29,6 +30,9 @@ const TEST_MESSAGE_ID: &str = "0x6ae9a99190641b9ed0c07143340612dde0e9cb7deaa5fe0
 const TEST_REFUND_ADDRESS: &str =
     "0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe";
 const TEST_GAS_OVERHEAD_AMOUNT: u64 = 100000;
+const TEST_GAS_MULTIPLIER_BPS: u64 = 12000;
+const TEST_MIN_GAS: u64 = 1_000_000;
+const BPS_SCALE: u64 = 10000;
 const NON_OWNER_PRIVATE_KEY: &str =
     "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
 
@@ -49,18 +53,8 @@ async fn get_contract_instances() -> (
     .await;
     let wallet = wallets.pop().unwrap();
 
-    let test_igp_id = Contract::deploy(
-        "../interchain-gas-paymaster-test/out/debug/interchain-gas-paymaster-test.bin",
-        &wallet,
-        DeployConfiguration::default().set_storage_configuration(StorageConfiguration::new(
-            "../interchain-gas-paymaster-test/out/debug/interchain-gas-paymaster-test-storage_slots.json".to_string(),
-            vec![],
-        )),
-    )
-    .await
-    .unwrap();
-
-    let test_igp = TestInterchainGasPaymaster::new(test_igp_id.clone(), wallet.clone());
+    let test_igp = deploy_test_igp(&wallet, [0u8; 32]).await;
+    let test_igp_id = test_igp.contract_id().clone();
 
     let overhead_igp_configurables =
         OverheadIgpConfigurables::default().set_INNER_IGP_ID(Bits256(test_igp_id.hash().into()));
@@ -94,6 +88,8 @@ async fn get_contract_instances() -> (
         .set_destination_gas_overheads(vec![GasOverheadConfig {
             domain: TEST_DESTINATION_DOMAIN,
             gas_overhead: TEST_GAS_OVERHEAD_AMOUNT,
+            gas_multiplier_bps: 0,
+            min_gas: 0,
         }])
         .call()
         .await
@@ -102,6 +98,40 @@ async fn get_contract_instances() -> (
     (overhead_igp, test_igp)
 }
 
+async fn deploy_test_igp(
+    wallet: &WalletUnlocked,
+    salt: [u8; 32],
+) -> TestInterchainGasPaymaster<WalletUnlocked> {
+    let test_igp_id = Contract::deploy(
+        "../interchain-gas-paymaster-test/out/debug/interchain-gas-paymaster-test.bin",
+        wallet,
+        DeployConfiguration::default()
+            .set_storage_configuration(StorageConfiguration::new(
+                "../interchain-gas-paymaster-test/out/debug/interchain-gas-paymaster-test-storage_slots.json".to_string(),
+                vec![],
+            ))
+            .set_salt(Salt::from(salt)),
+    )
+    .await
+    .unwrap();
+
+    TestInterchainGasPaymaster::new(test_igp_id, wallet.clone())
+}
+
+async fn set_multiplier_and_min_gas(overhead_igp: &OverheadIgp<WalletUnlocked>) {
+    overhead_igp
+        .methods()
+        .set_destination_gas_overheads(vec![GasOverheadConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            gas_overhead: TEST_GAS_OVERHEAD_AMOUNT,
+            gas_multiplier_bps: TEST_GAS_MULTIPLIER_BPS,
+            min_gas: TEST_MIN_GAS,
+        }])
+        .call()
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn test_inner_igp_set() {
     let (overhead_igp, test_igp) = get_contract_instances().await;
@@ -117,6 +147,105 @@ async fn test_inner_igp_set() {
     assert_eq!(inner_igp_id, Bits256(test_igp.contract_id().hash().into()));
 }
 
+// ============ set_inner_igp ============
+
+#[tokio::test]
+async fn test_set_inner_igp() {
+    let (overhead_igp, _) = get_contract_instances().await;
+
+    let new_test_igp = deploy_test_igp(&overhead_igp.account(), [1u8; 32]).await;
+    let new_inner_igp = Bits256(new_test_igp.contract_id().hash().into());
+
+    let call = overhead_igp
+        .methods()
+        .set_inner_igp(new_inner_igp)
+        .call()
+        .await
+        .unwrap();
+
+    let events = call.get_logs_with_type::<InnerIgpSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![InnerIgpSetEvent {
+            inner_igp: new_inner_igp
+        }]
+    );
+
+    assert_eq!(
+        overhead_igp
+            .methods()
+            .inner_igp()
+            .simulate()
+            .await
+            .unwrap()
+            .value,
+        new_inner_igp,
+    );
+
+    // Calls are now forwarded to the new inner IGP
+    let call = overhead_igp
+        .methods()
+        .quote_gas_payment(TEST_DESTINATION_DOMAIN, TEST_GAS_AMOUNT)
+        .estimate_tx_dependencies(Some(5))
+        .await
+        .unwrap()
+        .simulate()
+        .await
+        .unwrap();
+    let events = new_test_igp
+        .log_decoder()
+        .get_logs_with_type::<test_igp_contract::QuoteGasPaymentCalled>(&call.receipts)
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![test_igp_contract::QuoteGasPaymentCalled {
+            destination_domain: TEST_DESTINATION_DOMAIN,
+            gas_amount: TEST_GAS_AMOUNT + TEST_GAS_OVERHEAD_AMOUNT,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_set_inner_igp_reverts_if_zero() {
+    let (overhead_igp, _) = get_contract_instances().await;
+
+    let call = overhead_igp
+        .methods()
+        .set_inner_igp(Bits256([0u8; 32]))
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("zero inner IGP")
+    );
+}
+
+#[tokio::test]
+async fn test_set_inner_igp_reverts_if_not_owner() {
+    let (overhead_igp, _) = get_contract_instances().await;
+
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&overhead_igp.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    let call = overhead_igp
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_inner_igp(Bits256([1u8; 32]))
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
 // ============ pay_for_gas ============
 
 #[tokio::test]
@@ -179,6 +308,93 @@ async fn test_pay_for_gas() {
     );
 }
 
+#[tokio::test]
+async fn test_pay_for_gas_with_multiplier_and_min_gas() {
+    let (overhead_igp, test_igp) = get_contract_instances().await;
+
+    set_multiplier_and_min_gas(&overhead_igp).await;
+
+    let message_id = Bits256::from_hex_str(TEST_MESSAGE_ID).unwrap();
+    let refund_address = Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap());
+
+    // Small enough that the minimum gas applies
+    let small_gas_amount = 100;
+    // Large enough that the multiplied gas amount exceeds the minimum
+    let large_gas_amount = TEST_MIN_GAS;
+
+    for (gas_amount, expected_gas_amount) in [
+        (small_gas_amount, TEST_MIN_GAS),
+        (
+            large_gas_amount,
+            (large_gas_amount + TEST_GAS_OVERHEAD_AMOUNT) * TEST_GAS_MULTIPLIER_BPS / BPS_SCALE,
+        ),
+    ] {
+        let call = overhead_igp
+            .methods()
+            .pay_for_gas(
+                message_id,
+                TEST_DESTINATION_DOMAIN,
+                gas_amount,
+                refund_address.clone(),
+            )
+            .call_params(
+                CallParameters::default()
+                    .set_asset_id(BASE_ASSET_ID)
+                    .set_amount(1),
+            )
+            .unwrap()
+            .estimate_tx_dependencies(Some(5))
+            .await
+            .unwrap()
+            .call()
+            .await
+            .unwrap();
+
+        let events = test_igp
+            .log_decoder()
+            .get_logs_with_type::<test_igp_contract::PayForGasCalled>(&call.receipts)
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![test_igp_contract::PayForGasCalled {
+                message_id,
+                destination_domain: TEST_DESTINATION_DOMAIN,
+                gas_amount: expected_gas_amount,
+                refund_address: refund_address.clone(),
+            }]
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_reverts_if_gas_amount_overflows() {
+    let (overhead_igp, _) = get_contract_instances().await;
+
+    set_multiplier_and_min_gas(&overhead_igp).await;
+
+    let call = overhead_igp
+        .methods()
+        .pay_for_gas(
+            Bits256::from_hex_str(TEST_MESSAGE_ID).unwrap(),
+            TEST_DESTINATION_DOMAIN,
+            u64::MAX - TEST_GAS_OVERHEAD_AMOUNT,
+            Identity::Address(Address::from_str(TEST_REFUND_ADDRESS).unwrap()),
+        )
+        .call_params(
+            CallParameters::default()
+                .set_asset_id(BASE_ASSET_ID)
+                .set_amount(1),
+        )
+        .unwrap()
+        .estimate_tx_dependencies(Some(5))
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("gas amount overflow")
+    );
+}
+
 // ============ quote_gas_payment ============
 
 #[tokio::test]
@@ -209,6 +425,51 @@ async fn test_quote_gas_payment() {
     );
 }
 
+#[tokio::test]
+async fn test_quote_gas_payment_with_multiplier_and_min_gas() {
+    let (overhead_igp, test_igp) = get_contract_instances().await;
+
+    set_multiplier_and_min_gas(&overhead_igp).await;
+
+    let gas_amount = TEST_MIN_GAS;
+    let expected_gas_amount =
+        (gas_amount + TEST_GAS_OVERHEAD_AMOUNT) * TEST_GAS_MULTIPLIER_BPS / BPS_SCALE;
+
+    let call = overhead_igp
+        .methods()
+        .quote_gas_payment(TEST_DESTINATION_DOMAIN, gas_amount)
+        .estimate_tx_dependencies(Some(5))
+        .await
+        .unwrap()
+        .simulate()
+        .await
+        .unwrap();
+
+    let events = test_igp
+        .log_decoder()
+        .get_logs_with_type::<test_igp_contract::QuoteGasPaymentCalled>(&call.receipts)
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![test_igp_contract::QuoteGasPaymentCalled {
+            destination_domain: TEST_DESTINATION_DOMAIN,
+            gas_amount: expected_gas_amount,
+        }]
+    );
+
+    // The forwarded gas amount is also exposed directly
+    assert_eq!(
+        overhead_igp
+            .methods()
+            .destination_gas_amount(TEST_DESTINATION_DOMAIN, gas_amount)
+            .simulate()
+            .await
+            .unwrap()
+            .value,
+        expected_gas_amount,
+    );
+}
+
 // ============ set_destination_gas_overheads ============
 
 #[tokio::test]
@@ -219,10 +480,14 @@ async fn test_set_destination_gas_overheads() {
         GasOverheadConfig {
             domain: TEST_DESTINATION_DOMAIN + 1,
             gas_overhead: TEST_GAS_OVERHEAD_AMOUNT + 1,
+            gas_multiplier_bps: 12000,
+            min_gas: 0,
         },
         GasOverheadConfig {
             domain: TEST_DESTINATION_DOMAIN + 2,
             gas_overhead: TEST_GAS_OVERHEAD_AMOUNT + 2,
+            gas_multiplier_bps: 0,
+            min_gas: 1_000_000,
         },
     ];
 
@@ -247,6 +512,31 @@ async fn test_set_destination_gas_overheads() {
                 .value,
             config.gas_overhead,
         );
+        assert_eq!(
+            overhead_igp
+                .methods()
+                .destination_gas_multiplier_bps(config.domain)
+                .simulate()
+                .await
+                .unwrap()
+                .value,
+            // Zero is treated as no multiplier
+            if config.gas_multiplier_bps == 0 {
+                BPS_SCALE
+            } else {
+                config.gas_multiplier_bps
+            },
+        );
+        assert_eq!(
+            overhead_igp
+                .methods()
+                .destination_min_gas(config.domain)
+                .simulate()
+                .await
+                .unwrap()
+                .value,
+            config.min_gas,
+        );
     }
 
     let events = call
@@ -261,6 +551,28 @@ async fn test_set_destination_gas_overheads() {
     );
 }
 
+#[tokio::test]
+async fn test_set_destination_gas_overheads_reverts_if_multiplier_below_one() {
+    let (overhead_igp, _) = get_contract_instances().await;
+
+    let call = overhead_igp
+        .methods()
+        .set_destination_gas_overheads(vec![GasOverheadConfig {
+            domain: TEST_DESTINATION_DOMAIN,
+            gas_overhead: TEST_GAS_OVERHEAD_AMOUNT,
+            gas_multiplier_bps: BPS_SCALE - 1,
+            min_gas: 0,
+        }])
+        .call()
+        .await;
+
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("gas multiplier below 1x")
+    );
+}
+
 #[tokio::test]
 async fn test_set_destination_gas_overheads_reverts_if_not_owner() {
     let (overhead_igp, _) = get_contract_instances().await;
@@ -277,10 +589,15 @@ async fn test_set_destination_gas_overheads_reverts_if_not_owner() {
         .set_destination_gas_overheads(vec![GasOverheadConfig {
             domain: TEST_DESTINATION_DOMAIN,
             gas_overhead: TEST_GAS_OVERHEAD_AMOUNT,
+            gas_multiplier_bps: 0,
+            min_gas: 0,
         }])
         .call()
         .await;
 
     assert!(call.is_err());
-    assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
 }
@@ -0,0 +1,104 @@
+contract;
+
+use std::{auth::msg_sender, block::timestamp, logging::log};
+
+use hyperlane_interfaces::{igp::{GasOracle, RemoteGasData}, ownable::Ownable};
+
+use ownership::{data_structures::State, owner, set_ownership, transfer_ownership};
+
+use std_lib_extended::option::*;
+
+/// A config for setting remote gas data.
+pub struct RemoteGasDataConfig {
+    domain: u32,
+    remote_gas_data: RemoteGasData,
+}
+
+/// Logged when a remote gas data config is set.
+pub struct RemoteGasDataSetEvent {
+    config: RemoteGasDataConfig,
+}
+
+abi UnpackedStorageGasOracle {
+    #[storage(read, write)]
+    fn set_remote_gas_data_configs(configs: Vec<RemoteGasDataConfig>);
+}
+
+storage {
+    /// Domain -> gas data, unpacked across multiple storage slots.
+    remote_gas_data: StorageMap<u32, RemoteGasData> = StorageMap {},
+    /// Domain -> timestamp (TAI64) of the block the gas data was last set in.
+    last_updated: StorageMap<u32, u64> = StorageMap {},
+    /// The max age in seconds of gas data before it's considered stale.
+    max_age: u64 = 0,
+}
+
+/// NOTE: This contract is for testing purposes only. It is not intended to be used in production.
+///
+/// This contract mirrors the storage gas oracle's owner path, but stores remote gas data
+/// without packing it. Intended to be used as a baseline when measuring the gas used by
+/// the storage gas oracle.
+impl GasOracle for Contract {
+    #[storage(read)]
+    fn get_remote_gas_data(domain: u32) -> RemoteGasData {
+        let last_updated = storage.last_updated.get(domain).expect("no remote gas data set for domain");
+
+        let max_age = storage.max_age;
+        require(max_age == 0 || timestamp() - last_updated <= max_age, "remote gas data is stale");
+
+        storage.remote_gas_data.get(domain).unwrap()
+    }
+}
+
+impl UnpackedStorageGasOracle for Contract {
+    #[storage(read, write)]
+    fn set_remote_gas_data_configs(configs: Vec<RemoteGasDataConfig>) {
+        let sender = msg_sender().unwrap();
+        let is_owner = match owner() {
+            State::Initialized(owner) => owner == sender,
+            _ => false,
+        };
+        require(is_owner, "!owner");
+
+        let count = configs.len();
+        let mut i = 0;
+        while i < count {
+            let config = configs.get(i).unwrap();
+            require(!contains_domain(configs, i, config.domain), "duplicate domain");
+            storage.remote_gas_data.insert(config.domain, config.remote_gas_data);
+            storage.last_updated.insert(config.domain, timestamp());
+
+            log(RemoteGasDataSetEvent { config });
+            i += 1;
+        }
+    }
+}
+
+/// Returns true if any of the first `end` configs are for `domain`.
+fn contains_domain(configs: Vec<RemoteGasDataConfig>, end: u64, domain: u32) -> bool {
+    let mut i = 0;
+    while i < end {
+        if configs.get(i).unwrap().domain == domain {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+impl Ownable for Contract {
+    #[storage(read)]
+    fn owner() -> State {
+        owner()
+    }
+
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        transfer_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        set_ownership(new_owner);
+    }
+}
@@ -13,8 +13,58 @@ pub struct RemoteGasDataSetEvent {
     config: RemoteGasDataConfig,
 }
 
+/// Logged when the max age of remote gas data is set.
+pub struct MaxAgeSetEvent {
+    max_age: u64,
+}
+
+/// Logged when an updater is permitted or forbidden from setting remote gas
+/// data. If `domain` is None, the permission applies to all domains.
+pub struct UpdaterSetEvent {
+    updater: Identity,
+    domain: Option<u32>,
+    allowed: bool,
+}
+
+/// Logged when the max deviation of updates made by updaters is set.
+pub struct MaxDeviationSetEvent {
+    max_deviation_bps: u64,
+}
+
+/// Logged when the min interval between updates made by updaters is set.
+pub struct MinUpdateIntervalSetEvent {
+    min_update_interval: u64,
+}
+
 /// A gas oracle with remote gas data in storage.
 abi StorageGasOracle {
     #[storage(read, write)]
     fn set_remote_gas_data_configs(configs: Vec<RemoteGasDataConfig>);
+
+    #[storage(read)]
+    fn last_updated(domain: u32) -> Option<u64>;
+
+    #[storage(read)]
+    fn max_age() -> u64;
+
+    #[storage(read, write)]
+    fn set_max_age(max_age: u64);
+
+    #[storage(read)]
+    fn is_updater(updater: Identity, domain: u32) -> bool;
+
+    #[storage(read, write)]
+    fn set_updater(updater: Identity, domain: Option<u32>, allowed: bool);
+
+    #[storage(read)]
+    fn max_deviation_bps() -> u64;
+
+    #[storage(read, write)]
+    fn set_max_deviation_bps(max_deviation_bps: u64);
+
+    #[storage(read)]
+    fn min_update_interval() -> u64;
+
+    #[storage(read, write)]
+    fn set_min_update_interval(min_update_interval: u64);
 }
@@ -2,42 +2,263 @@ contract;
 
 mod interface;
 
-use std::{logging::log, u128::U128};
+use std::{auth::msg_sender, block::timestamp, logging::log, u128::U128, u256::U256};
 
-use hyperlane_interfaces::{igp::{GasOracle, RemoteGasData}, ownable::{Ownable}};
+use hyperlane_interfaces::{igp::{FallibleGasOracle, GasOracle, PackedRemoteGasData, RemoteGasData}, ownable::{Ownable}};
 
 use ownership::{data_structures::State, only_owner, owner, set_ownership, transfer_ownership};
 
-use interface::{RemoteGasDataConfig, RemoteGasDataSetEvent, StorageGasOracle};
+use std_lib_extended::option::*;
+
+use interface::{
+    MaxAgeSetEvent,
+    MaxDeviationSetEvent,
+    MinUpdateIntervalSetEvent,
+    RemoteGasDataConfig,
+    RemoteGasDataSetEvent,
+    StorageGasOracle,
+    UpdaterSetEvent,
+};
+
+/// The scale of basis points. 1e4.
+const BPS_SCALE: u64 = 10_000;
 
 storage {
-    remote_gas_data: StorageMap<u32, RemoteGasData> = StorageMap {},
+    /// Domain -> gas data, packed into a single storage slot.
+    remote_gas_data: StorageMap<u32, PackedRemoteGasData> = StorageMap {},
+    /// Domain -> timestamp (TAI64) of the block the gas data was last set in.
+    last_updated: StorageMap<u32, u64> = StorageMap {},
+    /// The max age in seconds of gas data before it's considered stale.
+    /// Zero means gas data never becomes stale.
+    max_age: u64 = 0,
+    /// Updaters permitted to set gas data for all domains.
+    updaters: StorageMap<Identity, bool> = StorageMap {},
+    /// (Updater, domain) -> whether the updater is permitted to set gas data for the domain.
+    domain_updaters: StorageMap<(Identity, u32), bool> = StorageMap {},
+    /// The max deviation in basis points of the exchange rate and gas price
+    /// in a single update made by an updater. Zero means updates are unbounded.
+    max_deviation_bps: u64 = 0,
+    /// The min time in seconds between updates to a domain's gas data made by
+    /// updaters while the max deviation is set.
+    min_update_interval: u64 = 0,
 }
 
 impl GasOracle for Contract {
     /// Gets the gas data from storage.
+    /// Reverts if no gas data has been set for the domain, or if the
+    /// gas data is older than the max age.
     #[storage(read)]
     fn get_remote_gas_data(domain: u32) -> RemoteGasData {
-        storage.remote_gas_data.get(domain).unwrap_or(RemoteGasData::default())
+        let last_updated = storage.last_updated.get(domain).expect("no remote gas data set for domain");
+
+        let max_age = storage.max_age;
+        require(max_age == 0 || timestamp() - last_updated <= max_age, "remote gas data is stale");
+
+        storage.remote_gas_data.get(domain).unwrap().unpack()
+    }
+}
+
+impl FallibleGasOracle for Contract {
+    /// Gets the gas data from storage, or None if no gas data has been set
+    /// for the domain or if the gas data is older than the max age.
+    #[storage(read)]
+    fn try_get_remote_gas_data(domain: u32) -> Option<RemoteGasData> {
+        let last_updated = match storage.last_updated.get(domain) {
+            Option::Some(last_updated) => last_updated,
+            Option::None => return Option::None,
+        };
+
+        let max_age = storage.max_age;
+        if max_age != 0 && timestamp() - last_updated > max_age {
+            return Option::None;
+        }
+
+        Option::Some(storage.remote_gas_data.get(domain).unwrap().unpack())
     }
 }
 
 impl StorageGasOracle for Contract {
-    /// Sets the gas data for a given domain. Only callable by the owner.
+    /// Sets the gas data for a given domain. Callable by the owner, or by an
+    /// updater permitted to set gas data for every domain in `configs`.
+    /// Updates made by updaters must be within the max deviation.
     #[storage(read, write)]
     fn set_remote_gas_data_configs(configs: Vec<RemoteGasDataConfig>) {
-        only_owner();
+        let sender = msg_sender().unwrap();
+        let is_owner = match owner() {
+            State::Initialized(owner) => owner == sender,
+            _ => false,
+        };
 
         let count = configs.len();
         let mut i = 0;
         while i < count {
             let config = configs.get(i).unwrap();
-            storage.remote_gas_data.insert(config.domain, config.remote_gas_data);
+            // Each update must be checked against the stored gas data, so
+            // a domain can only be set once per call.
+            require(!contains_domain(configs, i, config.domain), "duplicate domain");
+            if !is_owner {
+                require(is_updater(sender, config.domain), "!updater");
+                require_within_max_deviation(config);
+            }
+            // Reverts if the gas data can't be packed.
+            storage.remote_gas_data.insert(config.domain, config.remote_gas_data.pack());
+            storage.last_updated.insert(config.domain, timestamp());
 
             log(RemoteGasDataSetEvent { config });
             i += 1;
         }
     }
+
+    /// Gets the timestamp (TAI64) of the block the gas data for a domain
+    /// was last set in, or None if it has never been set.
+    #[storage(read)]
+    fn last_updated(domain: u32) -> Option<u64> {
+        storage.last_updated.get(domain)
+    }
+
+    /// Gets the max age in seconds of gas data before it's considered stale.
+    #[storage(read)]
+    fn max_age() -> u64 {
+        storage.max_age
+    }
+
+    /// Sets the max age in seconds of gas data before it's considered stale.
+    /// Zero disables staleness checks. Only callable by the owner.
+    #[storage(read, write)]
+    fn set_max_age(max_age: u64) {
+        only_owner();
+
+        storage.max_age = max_age;
+        log(MaxAgeSetEvent { max_age });
+    }
+
+    /// Returns true if `updater` is permitted to set gas data for `domain`.
+    #[storage(read)]
+    fn is_updater(updater: Identity, domain: u32) -> bool {
+        is_updater(updater, domain)
+    }
+
+    /// Permits or forbids `updater` from setting gas data for `domain`, or
+    /// for all domains if `domain` is None. Only callable by the owner.
+    #[storage(read, write)]
+    fn set_updater(updater: Identity, domain: Option<u32>, allowed: bool) {
+        only_owner();
+
+        match domain {
+            Option::Some(domain) => storage.domain_updaters.insert((updater, domain), allowed),
+            Option::None => storage.updaters.insert(updater, allowed),
+        }
+        log(UpdaterSetEvent {
+            updater,
+            domain,
+            allowed,
+        });
+    }
+
+    /// Gets the max deviation in basis points of updates made by updaters.
+    #[storage(read)]
+    fn max_deviation_bps() -> u64 {
+        storage.max_deviation_bps
+    }
+
+    /// Sets the max deviation in basis points of updates made by updaters.
+    /// Zero means updates are unbounded. Only callable by the owner.
+    #[storage(read, write)]
+    fn set_max_deviation_bps(max_deviation_bps: u64) {
+        only_owner();
+
+        storage.max_deviation_bps = max_deviation_bps;
+        log(MaxDeviationSetEvent { max_deviation_bps });
+    }
+
+    /// Gets the min time in seconds between updates to a domain's gas data made by updaters.
+    #[storage(read)]
+    fn min_update_interval() -> u64 {
+        storage.min_update_interval
+    }
+
+    /// Sets the min time in seconds between updates to a domain's gas data made
+    /// by updaters. Only callable by the owner.
+    #[storage(read, write)]
+    fn set_min_update_interval(min_update_interval: u64) {
+        only_owner();
+
+        storage.min_update_interval = min_update_interval;
+        log(MinUpdateIntervalSetEvent { min_update_interval });
+    }
+}
+
+/// Returns true if `updater` is permitted to set gas data for `domain`.
+#[storage(read)]
+fn is_updater(updater: Identity, domain: u32) -> bool {
+    storage.updaters.get(updater).unwrap_or(false) || storage.domain_updaters.get((updater, domain)).unwrap_or(false)
+}
+
+/// Returns true if any of the first `end` configs are for `domain`.
+fn contains_domain(configs: Vec<RemoteGasDataConfig>, end: u64, domain: u32) -> bool {
+    let mut i = 0;
+    while i < end {
+        if configs.get(i).unwrap().domain == domain {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Reverts if the max deviation is set and the config's exchange rate or gas
+/// price deviate too far from the existing gas data. Updaters may not set the
+/// initial gas data for a domain or change its token decimals while the max
+/// deviation is set.
+///
+/// So the deviation can't be compounded by updating a domain repeatedly,
+/// updaters may only update a domain's gas data once per block timestamp,
+/// and no sooner than the min update interval after it was last updated.
+#[storage(read)]
+fn require_within_max_deviation(config: RemoteGasDataConfig) {
+    let max_deviation_bps = storage.max_deviation_bps;
+    if max_deviation_bps == 0 {
+        return;
+    }
+
+    let last_updated = storage.last_updated.get(config.domain).expect("no remote gas data set for domain");
+    let now = timestamp();
+    require(now > last_updated && now - last_updated >= storage.min_update_interval, "remote gas data updated too recently");
+
+    let current = storage.remote_gas_data.get(config.domain).expect("no remote gas data set for domain").unpack();
+    let new = config.remote_gas_data;
+    require(new.token_decimals == current.token_decimals, "token decimals changed");
+    require(within_deviation(current.token_exchange_rate, new.token_exchange_rate, max_deviation_bps), "exchange rate deviation too large");
+    require(within_deviation(current.gas_price, new.gas_price, max_deviation_bps), "gas price deviation too large");
+}
+
+/// Returns true if `new` is within `max_deviation_bps` basis points of `current`.
+fn within_deviation(current: U128, new: U128, max_deviation_bps: u64) -> bool {
+    let current = U256::from(current);
+    let new = U256::from(new);
+    let difference = if new > current { new - current } else { current - new };
+
+    // difference / current <= max_deviation_bps / BPS_SCALE
+    !(difference * U256::from((0, 0, 0, BPS_SCALE)) > current * U256::from((0, 0, 0, max_deviation_bps)))
+}
+
+#[test()]
+fn test_within_deviation() {
+    let current = U128::from((0, 10_000));
+
+    // Equal values are always within the deviation.
+    assert(within_deviation(current, current, 1));
+
+    // 1% deviation in either direction.
+    assert(within_deviation(current, U128::from((0, 10_100)), 100));
+    assert(within_deviation(current, U128::from((0, 9_900)), 100));
+    assert(!within_deviation(current, U128::from((0, 10_101)), 100));
+    assert(!within_deviation(current, U128::from((0, 9_899)), 100));
+
+    // Any change from zero is an infinite deviation.
+    let zero = U128::from((0, 0));
+    assert(within_deviation(zero, zero, 100));
+    assert(!within_deviation(zero, U128::from((0, 1)), 100));
 }
 
 impl Ownable for Contract {
@@ -1,6 +1,7 @@
+use chrono::{Duration, Utc};
 use fuels::{prelude::*, tx::ContractId, types::Identity};
 
-use test_utils::{funded_wallet_with_private_key, get_revert_reason};
+use test_utils::{decode_revert_reason, funded_wallet_with_private_key, RevertReason};
 
 // Load abi from json
 abigen!(Contract(
@@ -21,6 +22,8 @@ impl From<u64> for U128 {
 
 const NON_OWNER_PRIVATE_KEY: &str =
     "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
+const UPDATER_PRIVATE_KEY: &str =
+    "0x2ef987da35e5b389bb47cc4ec024ce0c37e5defd00de35fe61db6f50d1a858a1";
 
 async fn get_contract_instance() -> (StorageGasOracle<WalletUnlocked>, ContractId) {
     // Launch a local network and deploy the contract
@@ -82,6 +85,21 @@ fn get_test_remote_gas_data_configs() -> Vec<RemoteGasDataConfig> {
     ]
 }
 
+/// Produces a block `seconds` from now, so later blocks are at least that far in the future.
+async fn produce_block_after(oracle: &StorageGasOracle<WalletUnlocked>, seconds: i64) {
+    let provider = oracle.account().provider().unwrap().clone();
+    provider
+        .produce_blocks(
+            1,
+            Some(TimeParameters {
+                start_time: Utc::now() + Duration::seconds(seconds),
+                block_time_interval: Duration::seconds(1),
+            }),
+        )
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn test_set_remote_gas_data_configs_and_get_remote_gas_data() {
     let (oracle, _) = get_contract_instance().await;
@@ -121,27 +139,238 @@ async fn test_set_remote_gas_data_configs_and_get_remote_gas_data() {
 }
 
 #[tokio::test]
-async fn test_exchange_rate_and_gas_price_unknown_domain() {
+async fn test_set_remote_gas_data_configs_reverts_if_duplicate_domain() {
+    let (oracle, _) = get_contract_instance().await;
+
+    let mut configs = get_test_remote_gas_data_configs();
+    configs.push(configs[0].clone());
+
+    let call = oracle
+        .methods()
+        .set_remote_gas_data_configs(configs)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("duplicate domain")
+    );
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_reverts_if_unknown_domain() {
+    let (oracle, _) = get_contract_instance().await;
+
+    let call = oracle.methods().get_remote_gas_data(1234).simulate().await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("no remote gas data set for domain")
+    );
+}
+
+#[tokio::test]
+async fn test_get_remote_gas_data_reverts_if_stale() {
     let (oracle, _) = get_contract_instance().await;
 
-    let RemoteGasData {
-        token_exchange_rate,
-        gas_price,
-        token_decimals,
-    } = oracle
+    let max_age = 3600u64;
+    oracle.methods().set_max_age(max_age).call().await.unwrap();
+
+    let configs = get_test_remote_gas_data_configs();
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(configs.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Fresh data can be read
+    let domain = configs[0].domain;
+    let remote_gas_data = oracle
         .methods()
-        .get_remote_gas_data(1234)
+        .get_remote_gas_data(domain)
         .simulate()
         .await
         .unwrap()
         .value;
-    assert_eq!(token_exchange_rate, 0.into());
-    assert_eq!(gas_price, 0.into());
-    assert_eq!(token_decimals, 9u8);
+    assert_eq!(remote_gas_data, configs[0].remote_gas_data);
+
+    // Produce a block after the max age has elapsed
+    let provider = oracle.account().provider().unwrap().clone();
+    provider
+        .produce_blocks(
+            1,
+            Some(TimeParameters {
+                start_time: Utc::now() + Duration::seconds(2 * max_age as i64),
+                block_time_interval: Duration::seconds(1),
+            }),
+        )
+        .await
+        .unwrap();
+
+    let call = oracle
+        .methods()
+        .get_remote_gas_data(domain)
+        .simulate()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("remote gas data is stale")
+    );
+
+    // Updating the data makes it fresh again
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(configs)
+        .call()
+        .await
+        .unwrap();
+    let call = oracle
+        .methods()
+        .get_remote_gas_data(domain)
+        .simulate()
+        .await;
+    assert!(call.is_ok());
 }
 
+// ============ try_get_remote_gas_data ============
+
 #[tokio::test]
-async fn test_set_remote_gas_data_configs_reverts_if_not_owner() {
+async fn test_try_get_remote_gas_data() {
+    let (oracle, _) = get_contract_instance().await;
+
+    let max_age = 3600u64;
+    oracle.methods().set_max_age(max_age).call().await.unwrap();
+
+    let configs = get_test_remote_gas_data_configs();
+    let domain = configs[0].domain;
+
+    // None for an unknown domain
+    let remote_gas_data = oracle
+        .methods()
+        .try_get_remote_gas_data(domain)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(remote_gas_data, None);
+
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(configs.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let remote_gas_data = oracle
+        .methods()
+        .try_get_remote_gas_data(domain)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(remote_gas_data, Some(configs[0].remote_gas_data.clone()));
+
+    // None once the data is stale
+    let provider = oracle.account().provider().unwrap().clone();
+    provider
+        .produce_blocks(
+            1,
+            Some(TimeParameters {
+                start_time: Utc::now() + Duration::seconds(2 * max_age as i64),
+                block_time_interval: Duration::seconds(1),
+            }),
+        )
+        .await
+        .unwrap();
+
+    let remote_gas_data = oracle
+        .methods()
+        .try_get_remote_gas_data(domain)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(remote_gas_data, None);
+}
+
+// ============ last_updated ============
+
+#[tokio::test]
+async fn test_last_updated() {
+    let (oracle, _) = get_contract_instance().await;
+
+    let configs = get_test_remote_gas_data_configs();
+    let domain = configs[0].domain;
+
+    let last_updated = oracle
+        .methods()
+        .last_updated(domain)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(last_updated, None);
+
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(configs)
+        .call()
+        .await
+        .unwrap();
+
+    let last_updated = oracle
+        .methods()
+        .last_updated(domain)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(last_updated.is_some());
+}
+
+// ============ set_max_age ============
+
+#[tokio::test]
+async fn test_set_max_age() {
+    let (oracle, _) = get_contract_instance().await;
+
+    // Staleness checks are initially disabled
+    let max_age = oracle.methods().max_age().simulate().await.unwrap().value;
+    assert_eq!(max_age, 0);
+
+    let call = oracle.methods().set_max_age(3600).call().await.unwrap();
+    let events = call.get_logs_with_type::<MaxAgeSetEvent>().unwrap();
+    assert_eq!(events, vec![MaxAgeSetEvent { max_age: 3600 }]);
+
+    let max_age = oracle.methods().max_age().simulate().await.unwrap().value;
+    assert_eq!(max_age, 3600);
+}
+
+#[tokio::test]
+async fn test_set_max_age_reverts_if_not_owner() {
+    let (oracle, _) = get_contract_instance().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = oracle
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_max_age(3600)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_set_remote_gas_data_configs_reverts_if_not_owner_or_updater() {
     let (oracle, _) = get_contract_instance().await;
     let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
         .await
@@ -162,5 +391,464 @@ async fn test_set_remote_gas_data_configs_reverts_if_not_owner() {
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_reason(call.err().unwrap()), "NotOwner");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("!updater")
+    );
+}
+
+// ============ set_updater ============
+
+#[tokio::test]
+async fn test_set_updater() {
+    let (oracle, _) = get_contract_instance().await;
+    let updater = Identity::Address(Address::from([1u8; 32]));
+
+    let is_updater = oracle
+        .methods()
+        .is_updater(updater.clone(), 11111)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(!is_updater);
+
+    // Permit the updater for a single domain
+    let call = oracle
+        .methods()
+        .set_updater(updater.clone(), Some(11111), true)
+        .call()
+        .await
+        .unwrap();
+    let events = call.get_logs_with_type::<UpdaterSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![UpdaterSetEvent {
+            updater: updater.clone(),
+            domain: Some(11111),
+            allowed: true,
+        }]
+    );
+
+    for (domain, expected) in [(11111, true), (44444, false)] {
+        let is_updater = oracle
+            .methods()
+            .is_updater(updater.clone(), domain)
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(is_updater, expected);
+    }
+
+    // Permit the updater for all domains
+    oracle
+        .methods()
+        .set_updater(updater.clone(), None, true)
+        .call()
+        .await
+        .unwrap();
+
+    let is_updater = oracle
+        .methods()
+        .is_updater(updater.clone(), 44444)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(is_updater);
+
+    // Forbid the updater for all domains, leaving the domain permission
+    oracle
+        .methods()
+        .set_updater(updater.clone(), None, false)
+        .call()
+        .await
+        .unwrap();
+
+    for (domain, expected) in [(11111, true), (44444, false)] {
+        let is_updater = oracle
+            .methods()
+            .is_updater(updater.clone(), domain)
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(is_updater, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_set_updater_reverts_if_not_owner() {
+    let (oracle, _) = get_contract_instance().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+    let non_owner_identity = Identity::Address(non_owner_wallet.address().into());
+
+    let call = oracle
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_updater(non_owner_identity, None, true)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_updater_sets_remote_gas_data() {
+    let (oracle, _) = get_contract_instance().await;
+    let updater_wallet = funded_wallet_with_private_key(&oracle.account(), UPDATER_PRIVATE_KEY)
+        .await
+        .unwrap();
+    let updater_identity = Identity::Address(updater_wallet.address().into());
+
+    let configs = get_test_remote_gas_data_configs();
+
+    // Only permit the updater for the first domain
+    oracle
+        .methods()
+        .set_updater(updater_identity, Some(configs[0].domain), true)
+        .call()
+        .await
+        .unwrap();
+
+    let updater_oracle = oracle.with_account(updater_wallet).unwrap();
+
+    updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![configs[0].clone()])
+        .call()
+        .await
+        .unwrap();
+
+    let remote_gas_data = oracle
+        .methods()
+        .get_remote_gas_data(configs[0].domain)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(remote_gas_data, configs[0].remote_gas_data);
+
+    // Including a domain the updater isn't permitted for reverts
+    let call = updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(configs)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("!updater")
+    );
+}
+
+// ============ set_max_deviation_bps ============
+
+#[tokio::test]
+async fn test_set_max_deviation_bps() {
+    let (oracle, _) = get_contract_instance().await;
+
+    // Updates are initially unbounded
+    let max_deviation_bps = oracle
+        .methods()
+        .max_deviation_bps()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(max_deviation_bps, 0);
+
+    let call = oracle
+        .methods()
+        .set_max_deviation_bps(1000)
+        .call()
+        .await
+        .unwrap();
+    let events = call.get_logs_with_type::<MaxDeviationSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![MaxDeviationSetEvent {
+            max_deviation_bps: 1000
+        }]
+    );
+
+    let max_deviation_bps = oracle
+        .methods()
+        .max_deviation_bps()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(max_deviation_bps, 1000);
+}
+
+#[tokio::test]
+async fn test_set_max_deviation_bps_reverts_if_not_owner() {
+    let (oracle, _) = get_contract_instance().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = oracle
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_max_deviation_bps(1000)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_updater_bounded_by_max_deviation() {
+    let (oracle, _) = get_contract_instance().await;
+    let updater_wallet = funded_wallet_with_private_key(&oracle.account(), UPDATER_PRIVATE_KEY)
+        .await
+        .unwrap();
+    let updater_identity = Identity::Address(updater_wallet.address().into());
+
+    let config = get_test_remote_gas_data_configs()[0].clone();
+
+    oracle
+        .methods()
+        .set_updater(updater_identity, None, true)
+        .call()
+        .await
+        .unwrap();
+    // 10% max deviation
+    oracle
+        .methods()
+        .set_max_deviation_bps(1000)
+        .call()
+        .await
+        .unwrap();
+
+    let updater_oracle = oracle.with_account(updater_wallet).unwrap();
+
+    // The updater can't set the initial gas data
+    let call = updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![config.clone()])
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("no remote gas data set for domain")
+    );
+
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![config.clone()])
+        .call()
+        .await
+        .unwrap();
+
+    // 22222 -> 24444 is within 10%
+    let within_bounds = RemoteGasDataConfig {
+        remote_gas_data: RemoteGasData {
+            token_exchange_rate: 24444.into(),
+            ..config.remote_gas_data.clone()
+        },
+        ..config.clone()
+    };
+    produce_block_after(&oracle, 10).await;
+    updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![within_bounds.clone()])
+        .call()
+        .await
+        .unwrap();
+
+    // 33333 -> 36667 is outside of 10%
+    let out_of_bounds = RemoteGasDataConfig {
+        remote_gas_data: RemoteGasData {
+            gas_price: 36667.into(),
+            ..within_bounds.remote_gas_data.clone()
+        },
+        ..within_bounds.clone()
+    };
+    produce_block_after(&oracle, 20).await;
+    let call = updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![out_of_bounds.clone()])
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("gas price deviation too large")
+    );
+
+    // The owner isn't bounded
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![out_of_bounds])
+        .call()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_updater_rate_limited_while_bounded() {
+    let (oracle, _) = get_contract_instance().await;
+    let updater_wallet = funded_wallet_with_private_key(&oracle.account(), UPDATER_PRIVATE_KEY)
+        .await
+        .unwrap();
+    let updater_identity = Identity::Address(updater_wallet.address().into());
+
+    let config = get_test_remote_gas_data_configs()[0].clone();
+
+    oracle
+        .methods()
+        .set_updater(updater_identity, None, true)
+        .call()
+        .await
+        .unwrap();
+    // 10% max deviation
+    oracle
+        .methods()
+        .set_max_deviation_bps(1000)
+        .call()
+        .await
+        .unwrap();
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![config.clone()])
+        .call()
+        .await
+        .unwrap();
+
+    let updater_oracle = oracle.with_account(updater_wallet).unwrap();
+
+    // 22222 -> 24444 is within 10%, but repeating it compounds the deviation
+    let within_bounds = RemoteGasDataConfig {
+        remote_gas_data: RemoteGasData {
+            token_exchange_rate: 24444.into(),
+            ..config.remote_gas_data.clone()
+        },
+        ..config.clone()
+    };
+
+    // The updater can't update a domain more than once in a call
+    produce_block_after(&oracle, 10).await;
+    let call = updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![config.clone(), within_bounds.clone()])
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("duplicate domain")
+    );
+
+    let min_update_interval = 3600u64;
+    oracle
+        .methods()
+        .set_min_update_interval(min_update_interval)
+        .call()
+        .await
+        .unwrap();
+
+    // Nor before the min update interval has elapsed
+    let call = updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![within_bounds.clone()])
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("remote gas data updated too recently")
+    );
+
+    produce_block_after(&oracle, 2 * min_update_interval as i64).await;
+    updater_oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![within_bounds.clone()])
+        .call()
+        .await
+        .unwrap();
+
+    // The owner isn't rate limited
+    oracle
+        .methods()
+        .set_remote_gas_data_configs(vec![config])
+        .call()
+        .await
+        .unwrap();
+}
+
+// ============ set_min_update_interval ============
+
+#[tokio::test]
+async fn test_set_min_update_interval() {
+    let (oracle, _) = get_contract_instance().await;
+
+    let min_update_interval = oracle
+        .methods()
+        .min_update_interval()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(min_update_interval, 0);
+
+    let call = oracle
+        .methods()
+        .set_min_update_interval(3600)
+        .call()
+        .await
+        .unwrap();
+    let events = call
+        .get_logs_with_type::<MinUpdateIntervalSetEvent>()
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![MinUpdateIntervalSetEvent {
+            min_update_interval: 3600
+        }]
+    );
+
+    let min_update_interval = oracle
+        .methods()
+        .min_update_interval()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(min_update_interval, 3600);
+}
+
+#[tokio::test]
+async fn test_set_min_update_interval_reverts_if_not_owner() {
+    let (oracle, _) = get_contract_instance().await;
+    let non_owner_wallet = funded_wallet_with_private_key(&oracle.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = oracle
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_min_update_interval(3600)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
 }
@@ -4,7 +4,9 @@ use fuels::{
     types::{Bits256, B512},
 };
 use hyperlane_core::{utils::domain_hash, Checkpoint, Signable, H256};
-use test_utils::{bits256_to_h256, encode_multisig_metadata, h256_to_bits256};
+use test_utils::{
+    bits256_to_h256, encode_multisig_metadata, get_signer, h256_to_bits256, CheckpointSigner,
+};
 
 // Load abi from json
 abigen!(Contract(
@@ -17,6 +19,11 @@ const TEST_MAILBOX_DOMAIN: u32 = 420u32;
 const TEST_CHECKPOINT_ROOT: H256 = H256::repeat_byte(0xbu8);
 const TEST_CHECKPOINT_INDEX: u32 = 69u32;
 
+const TEST_VALIDATOR_0_PRIVATE_KEY: &str =
+    "2ef987da35e5b389bb47cc4ec024ce0c37e5defd00de35fe61db6f50d1a858a1";
+const TEST_VALIDATOR_1_PRIVATE_KEY: &str =
+    "411f401057d09d1d65d898ff48f775b0568e8a4cd1212e894b8b4c8820c75c3e";
+
 async fn get_contract_instance() -> (TestMultisigIsmMetadata<WalletUnlocked>, ContractId) {
     // Launch a local network and deploy the contract
     let mut wallets = launch_custom_provider_and_get_wallets(
@@ -162,6 +169,76 @@ async fn test_bytes_to_multisig_metadata() {
     );
 }
 
+#[tokio::test]
+async fn test_checkpoint_signer_metadata() {
+    let (instance, _id) = get_contract_instance().await;
+
+    let (checkpoint, expected_metadata) = get_test_checkpoint_and_metadata();
+    let checkpoint_signer = CheckpointSigner::new(
+        vec![
+            get_signer(TEST_VALIDATOR_0_PRIVATE_KEY),
+            get_signer(TEST_VALIDATOR_1_PRIVATE_KEY),
+        ],
+        checkpoint.mailbox_address,
+        checkpoint.mailbox_domain,
+    );
+    let proof = expected_metadata
+        .proof
+        .iter()
+        .cloned()
+        .map(bits256_to_h256)
+        .collect::<Vec<_>>();
+    let signatures = checkpoint_signer
+        .sign_checkpoint(checkpoint.root, checkpoint.index)
+        .await;
+    let metadata = checkpoint_signer
+        .metadata(checkpoint.root, checkpoint.index, &proof)
+        .await;
+    let threshold = signatures.len() as u64;
+
+    let (root, index, mailbox, proof) = instance
+        .methods()
+        .bytes_to_multisig_metadata_parts(Bytes(metadata.clone()), threshold)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let decoded_signatures = instance
+        .methods()
+        .bytes_to_multisig_metadata_signatures(Bytes(metadata), threshold)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let decoded_metadata = MultisigMetadata {
+        root,
+        index,
+        mailbox,
+        proof,
+        signatures: decoded_signatures,
+    };
+    assert_eq!(
+        decoded_metadata,
+        MultisigMetadata {
+            signatures,
+            ..expected_metadata
+        },
+    );
+
+    // The signed digest is the digest the contract verifies signatures against
+    let checkpoint_digest = instance
+        .methods()
+        .checkpoint_digest(decoded_metadata, checkpoint.mailbox_domain)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        bits256_to_h256(checkpoint_digest),
+        checkpoint_signer.checkpoint_digest(checkpoint.root, checkpoint.index),
+    );
+}
+
 /// Encodes a MultisigMetadata struct into a Vec<u8>
 /// with the format expected by the Sway contracts.
 impl Into<Vec<u8>> for MultisigMetadata {
@@ -1,29 +1,29 @@
 library;
 
-use std::{bytes::Bytes, vm::evm::evm_address::EvmAddress};
+use std::bytes::Bytes;
 
 use hyperlane_message::Message;
 
 use multisig_ism_metadata::MultisigMetadata;
 
-use hyperlane_interfaces::ModuleType;
+use hyperlane_interfaces::{ModuleType, validator_key::ValidatorKey};
 
 abi MultisigIsm {
     #[storage(read)]
     fn threshold(domain: u32) -> u8;
     #[storage(read)]
-    fn validators(domain: u32) -> Vec<EvmAddress>;
+    fn validators(domain: u32) -> Vec<ValidatorKey>;
     #[storage(read)]
-    fn validators_and_threshold(message: Bytes) -> (Vec<EvmAddress>, u8);
+    fn validators_and_threshold(message: Bytes) -> (Vec<ValidatorKey>, u8);
     #[storage(read)]
-    fn is_enrolled(domain: u32, validator: EvmAddress) -> bool;
+    fn is_enrolled(domain: u32, validator: ValidatorKey) -> bool;
 
     #[storage(read, write)]
-    fn enroll_validator(domain: u32, validator: EvmAddress);
+    fn enroll_validator(domain: u32, validator: ValidatorKey);
     #[storage(read, write)]
-    fn enroll_validators(domains: Vec<u32>, validators: Vec<Vec<EvmAddress>>);
+    fn enroll_validators(domains: Vec<u32>, validators: Vec<Vec<ValidatorKey>>);
     #[storage(read, write)]
-    fn unenroll_validator(domain: u32, validator: EvmAddress);
+    fn unenroll_validator(domain: u32, validator: ValidatorKey);
     #[storage(read, write)]
     fn set_threshold(domain: u32, threshold: u8);
     #[storage(read, write)]
@@ -2,15 +2,7 @@ contract;
 
 mod interface;
 
-use std::{
-    bytes::Bytes,
-    constants::ZERO_B256,
-    logging::log,
-    vm::evm::{
-        ecr::ec_recover_evm_address,
-        evm_address::EvmAddress,
-    },
-};
+use std::{bytes::Bytes, logging::log};
 
 use storagemapvec::StorageMapVec;
 
@@ -20,7 +12,15 @@ use merkle::StorageMerkleTree;
 
 use interface::MultisigIsm;
 
-use hyperlane_interfaces::{InterchainSecurityModule, ModuleType, ownable::Ownable};
+use hyperlane_interfaces::{
+    InterchainSecurityModule,
+    ModuleType,
+    ownable::Ownable,
+    validator_key::{
+        Signer,
+        ValidatorKey,
+    },
+};
 
 use ownership::{data_structures::State, only_owner, owner, set_ownership, transfer_ownership};
 
@@ -31,14 +31,15 @@ use std_lib_extended::{option::*, result::*};
 /// See https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/main/solidity/contracts/isms/MultisigIsm.sol
 /// for the reference implementation.
 storage {
-    validators: StorageMapVec<u32, EvmAddress> = StorageMapVec {},
+    /// Domain -> validators, each of which may sign with an EVM or Fuel key.
+    validators: StorageMapVec<u32, ValidatorKey> = StorageMapVec {},
     threshold: StorageMap<u32, u8> = StorageMap {},
 }
 
 /// Returns index of the validator on the multisig for the domain
 /// Currently O(n) but could be O(1) with a set data structure
 #[storage(read)]
-fn index_of(domain: u32, validator: EvmAddress) -> Option<u32> {
+fn index_of(domain: u32, validator: ValidatorKey) -> Option<u32> {
     let validators = storage.validators.to_vec(domain);
     let mut i: u32 = 0;
     let len = validators.len();
@@ -53,7 +54,7 @@ fn index_of(domain: u32, validator: EvmAddress) -> Option<u32> {
 
 /// Returns true if the validator is on the multisig for the domain
 #[storage(read)]
-fn is_enrolled(domain: u32, validator: EvmAddress) -> bool {
+fn is_enrolled(domain: u32, validator: ValidatorKey) -> bool {
     let len = storage.validators.len(domain);
     return index_of(domain, validator).is_some();
 }
@@ -85,10 +86,10 @@ pub fn verify_validator_signatures(
     while signature_index < threshold {
         let signature = metadata.signatures.get(signature_index).unwrap();
 
-        let signer = ec_recover_evm_address(signature, digest).expect("validator signature recovery failed");
+        let signer = Signer::recover(signature, digest).expect("validator signature recovery failed");
 
         // Loop through remaining validators until we find a match
-        while validator_index < validator_count && signer != validators.get(validator_index).unwrap() {
+        while validator_index < validator_count && !signer.is(validators.get(validator_index).unwrap()) {
             validator_index += 1;
         }
 
@@ -104,8 +105,8 @@ pub fn verify_validator_signatures(
 
 /// Enrolls a validator without updating the commitment.
 #[storage(read, write)]
-fn enroll_validator(domain: u32, validator: EvmAddress) {
-    require(validator != EvmAddress::from(ZERO_B256), "zero address");
+fn enroll_validator(domain: u32, validator: ValidatorKey) {
+    require(!validator.is_zero(), "zero address");
     require(!is_enrolled(domain, validator), "enrolled");
     storage.validators.push(domain, validator);
 }
@@ -124,7 +125,7 @@ fn threshold(domain: u32) -> u8 {
 
 /// Returns the validator set enrolled for the domain.
 #[storage(read)]
-fn validators(domain: u32) -> Vec<EvmAddress> {
+fn validators(domain: u32) -> Vec<ValidatorKey> {
     return storage.validators.to_vec(domain);
 }
 
@@ -154,12 +155,12 @@ impl MultisigIsm for Contract {
 
     /// Returns the validator set enrolled for the domain.
     #[storage(read)]
-    fn validators(domain: u32) -> Vec<EvmAddress> {
+    fn validators(domain: u32) -> Vec<ValidatorKey> {
         validators(domain)
     }
 
     #[storage(read)]
-    fn validators_and_threshold(message: Bytes) -> (Vec<EvmAddress>, u8) {
+    fn validators_and_threshold(message: Bytes) -> (Vec<ValidatorKey>, u8) {
         let message = EncodedMessage { bytes: message };
         let domain = message.origin();
         return (validators(domain), threshold(domain));
@@ -167,7 +168,7 @@ impl MultisigIsm for Contract {
 
     /// Returns true if the validator is enrolled for the domain.
     #[storage(read)]
-    fn is_enrolled(domain: u32, validator: EvmAddress) -> bool {
+    fn is_enrolled(domain: u32, validator: ValidatorKey) -> bool {
         return is_enrolled(domain, validator);
     }
 
@@ -182,14 +183,14 @@ impl MultisigIsm for Contract {
     /// Enrolls a validator for the domain (and updates commitment).
     /// Must not already be enrolled.
     #[storage(read, write)]
-    fn enroll_validator(domain: u32, validator: EvmAddress) {
+    fn enroll_validator(domain: u32, validator: ValidatorKey) {
         only_owner();
         enroll_validator(domain, validator);
     }
 
     /// Batches validator enrollment for a list of domains.
     #[storage(read, write)]
-    fn enroll_validators(domains: Vec<u32>, validators: Vec<Vec<EvmAddress>>) {
+    fn enroll_validators(domains: Vec<u32>, validators: Vec<Vec<ValidatorKey>>) {
         only_owner();
         let domain_len = domains.len();
         require(domain_len == validators.len(), "!length");
@@ -226,7 +227,7 @@ impl MultisigIsm for Contract {
 
     /// Unenrolls a validator for the domain (and updates commitment).
     #[storage(read, write)]
-    fn unenroll_validator(domain: u32, validator: EvmAddress) {
+    fn unenroll_validator(domain: u32, validator: ValidatorKey) {
         only_owner();
         let index = index_of(domain, validator);
         require(index.is_some(), "!enrolled");
@@ -1,17 +1,17 @@
+use std::str::FromStr;
+
 use ethers::prelude::rand;
 use fuels::{
     prelude::*,
-    tx::{ContractId, Receipt},
-    types::{Bits256, Bytes, EvmAddress, Identity, B512},
+    tx::ContractId,
+    types::{Address, Bits256, Bytes, Identity},
 };
 
-use hyperlane_core::{
-    accumulator::merkle::MerkleTree, Checkpoint, Decode, Encode, HyperlaneMessage, H256,
-};
+use hyperlane_core::{accumulator::merkle::MerkleTree, Encode, HyperlaneMessage, H256};
 use hyperlane_ethereum::Signers;
 use test_utils::{
-    bits256_to_h256, encode_multisig_metadata, evm_address, get_revert_string, get_signer,
-    sign_compact, zero_address,
+    bits256_to_h256, decode_revert_reason, dispatched_messages, encode_multisig_metadata,
+    evm_address, fuel_address, get_signer, zero_address, CheckpointSigner, RevertReason,
 };
 
 mod mailbox_contract {
@@ -102,7 +102,7 @@ async fn deploy_mailbox(wallet: WalletUnlocked) -> Mailbox<WalletUnlocked> {
     return Mailbox::new(mailbox_id, wallet);
 }
 
-async fn setup() -> (Vec<u32>, Vec<EvmAddress>, Vec<Signers>, Vec<u8>) {
+async fn setup() -> (Vec<u32>, Vec<ValidatorKey>, Vec<Signers>, Vec<u8>) {
     let domains = Vec::from([TEST_LOCAL_DOMAIN, TEST_REMOTE_DOMAIN]);
 
     let signers = Vec::from([
@@ -112,7 +112,7 @@ async fn setup() -> (Vec<u32>, Vec<EvmAddress>, Vec<Signers>, Vec<u8>) {
 
     let addresses = signers
         .iter()
-        .map(|signer| evm_address(signer))
+        .map(|signer| ValidatorKey::Evm(evm_address(signer)))
         .collect::<Vec<_>>();
 
     let thresholds = domains
@@ -132,22 +132,25 @@ async fn test_enroll_validator() {
     // validator cannot be zero address
     let call = instance
         .methods()
-        .enroll_validator(domains[0], zero_address())
+        .enroll_validator(domains[0], ValidatorKey::Evm(zero_address()))
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.err().unwrap()), "zero address");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("zero address")
+    );
 
     let call = instance
         .methods()
-        .enroll_validator(domains[0], addresses[0])
+        .enroll_validator(domains[0], addresses[0].clone())
         .call()
         .await;
     assert!(call.is_ok());
 
     let result = instance
         .methods()
-        .is_enrolled(domains[0], addresses[0])
+        .is_enrolled(domains[0], addresses[0].clone())
         .simulate()
         .await
         .unwrap();
@@ -156,11 +159,65 @@ async fn test_enroll_validator() {
     // validator already enrolled
     let call = instance
         .methods()
-        .enroll_validator(domains[0], addresses[0])
+        .enroll_validator(domains[0], addresses[0].clone())
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("enrolled")
+    );
+}
+
+#[tokio::test]
+async fn test_enroll_fuel_validator() {
+    let (instance, _id, _) = get_contract_instance().await;
+
+    let (domains, addresses, _, _) = setup().await;
+
+    // validator cannot be zero address
+    let call = instance
+        .methods()
+        .enroll_validator(domains[0], ValidatorKey::Fuel(Address::zeroed()))
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.err().unwrap()), "enrolled");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("zero address")
+    );
+
+    let fuel_validator = ValidatorKey::Fuel(fuel_address(TEST_VALIDATOR_0_PRIVATE_KEY));
+    let call = instance
+        .methods()
+        .enroll_validator(domains[0], fuel_validator.clone())
+        .call()
+        .await;
+    assert!(call.is_ok());
+
+    let result = instance
+        .methods()
+        .is_enrolled(domains[0], fuel_validator)
+        .simulate()
+        .await
+        .unwrap();
+    assert!(result.value);
+
+    // the EVM key of the same private key is a distinct validator
+    let result = instance
+        .methods()
+        .is_enrolled(domains[0], addresses[0].clone())
+        .simulate()
+        .await
+        .unwrap();
+    assert!(!result.value);
+
+    let call = instance
+        .methods()
+        .enroll_validator(domains[0], addresses[0].clone())
+        .call()
+        .await;
+    assert!(call.is_ok());
 }
 
 #[tokio::test]
@@ -171,16 +228,19 @@ async fn test_unenroll_validator() {
 
     let call = instance
         .methods()
-        .unenroll_validator(domains[0], addresses[0])
+        .unenroll_validator(domains[0], addresses[0].clone())
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.err().unwrap()), "!enrolled");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("!enrolled")
+    );
 
     for address in addresses.iter() {
         let call = instance
             .methods()
-            .enroll_validator(domains[0], *address)
+            .enroll_validator(domains[0], address.clone())
             .call()
             .await;
         assert!(call.is_ok());
@@ -189,7 +249,7 @@ async fn test_unenroll_validator() {
     let address = addresses.swap_remove(0);
     let call = instance
         .methods()
-        .unenroll_validator(domains[0], address)
+        .unenroll_validator(domains[0], address.clone())
         .call()
         .await;
     assert!(call.is_ok());
@@ -247,7 +307,10 @@ async fn test_enroll_validators() {
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.err().unwrap()), "!length");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("!length")
+    );
 }
 
 #[tokio::test]
@@ -259,7 +322,10 @@ async fn test_set_threshold() {
     // zero threshold
     let call = instance.methods().set_threshold(domains[0], 0).call().await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.err().unwrap()), "!range");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("!range")
+    );
 
     // threshold > validators[domain].length
     let call = instance
@@ -268,7 +334,10 @@ async fn test_set_threshold() {
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.err().unwrap()), "!range");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("!range")
+    );
 
     let validators = domains
         .iter()
@@ -339,7 +408,10 @@ async fn test_set_thresholds() {
         .call()
         .await;
     assert!(call.is_err());
-    assert_eq!(get_revert_string(call.err().unwrap()), "!length");
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("!length")
+    );
 }
 
 const TEST_MAILBOX_ADDRESS: H256 = H256::repeat_byte(0xau8);
@@ -365,6 +437,8 @@ async fn test_verify() {
         .call()
         .await;
 
+    let checkpoint_signer = CheckpointSigner::new(signers, TEST_MAILBOX_ADDRESS, TEST_LOCAL_DOMAIN);
+
     let mailbox = deploy_mailbox(wallet).await;
     let depth = 32;
     let mut tree = MerkleTree::create(&[], depth);
@@ -385,14 +459,9 @@ async fn test_verify() {
             .unwrap();
 
         // recover message from receipt log data
-        let log_receipt = &dispatch_call.receipts[1];
-        let log_data = if let Receipt::LogData { data, .. } = log_receipt {
-            data
-        } else {
-            panic!("Expected LogData receipt. Receipt: {:?}", log_receipt);
-        };
-
-        let message = HyperlaneMessage::read_from(&mut log_data.as_slice()).unwrap();
+        let message = dispatched_messages(&dispatch_call.receipts)
+            .unwrap()
+            .remove(0);
 
         // push the message commitment to the merkle tree
         let _ = tree.push_leaf(message.id(), depth);
@@ -406,19 +475,10 @@ async fn test_verify() {
             .value;
 
         // sign the checkpoint
-        let checkpoint = Checkpoint {
-            mailbox_address: TEST_MAILBOX_ADDRESS,
-            mailbox_domain: TEST_LOCAL_DOMAIN,
-            root: bits256_to_h256(root),
-            index,
-        };
-
-        let mut signatures: Vec<B512> = Vec::new();
-
-        for signer in signers.iter() {
-            let compact = sign_compact(signer, checkpoint).await;
-            signatures.push(compact);
-        }
+        let checkpoint = checkpoint_signer.checkpoint(bits256_to_h256(root), index);
+        let mut signatures = checkpoint_signer
+            .sign_checkpoint(checkpoint.root, checkpoint.index)
+            .await;
 
         // generate merkle proof
         let (leaf, mut proof) = tree.generate_proof(index as usize, depth);
@@ -462,8 +522,8 @@ async fn test_verify() {
             .await;
 
         assert!(bad_merkle.is_err());
-        let reason = get_revert_string(bad_merkle.err().unwrap());
-        assert_eq!(reason, "!merkle");
+        let reason = decode_revert_reason(bad_merkle.err().unwrap()).unwrap();
+        assert_eq!(reason, RevertReason::require("!merkle"));
 
         // Proof was previously reversed, so reverse it back to the original
         proof.reverse();
@@ -485,7 +545,84 @@ async fn test_verify() {
             .await;
 
         assert!(bad_sigs.is_err());
-        let reason = get_revert_string(bad_sigs.err().unwrap());
-        assert_eq!(reason, "!signatures");
+        let reason = decode_revert_reason(bad_sigs.err().unwrap()).unwrap();
+        assert_eq!(reason, RevertReason::require("!signatures"));
     }
 }
+
+#[tokio::test]
+async fn test_verify_fuel_validators() {
+    let (instance, _id, _) = get_contract_instance().await;
+
+    let (_, addresses, signers, _) = setup().await;
+
+    // validator 0 is enrolled by its Fuel address, validator 1 by its EVM address
+    let validators = vec![
+        ValidatorKey::Fuel(fuel_address(TEST_VALIDATOR_0_PRIVATE_KEY)),
+        addresses[1].clone(),
+    ];
+    let _ = instance
+        .methods()
+        .enroll_validators(vec![TEST_LOCAL_DOMAIN], vec![validators.clone()])
+        .call()
+        .await;
+    let _ = instance
+        .methods()
+        .set_threshold(TEST_LOCAL_DOMAIN, validators.len() as u8)
+        .call()
+        .await;
+
+    let message = HyperlaneMessage {
+        version: 0,
+        nonce: 0,
+        origin: TEST_LOCAL_DOMAIN,
+        sender: H256::repeat_byte(0xcu8),
+        destination: TEST_REMOTE_DOMAIN,
+        recipient: H256::from_str(TEST_RECIPIENT).unwrap(),
+        body: vec![1, 2, 3],
+    };
+
+    let mut tree = MerkleTree::create(&[], 32);
+    let _ = tree.push_leaf(message.id(), 32);
+
+    let metadata = Bytes(
+        CheckpointSigner::new(signers, TEST_MAILBOX_ADDRESS, TEST_LOCAL_DOMAIN)
+            .message_metadata(&message, &tree)
+            .await,
+    );
+    let message_bytes = Bytes(message.to_vec());
+
+    let result = instance
+        .methods()
+        .verify(metadata.clone(), message_bytes.clone())
+        .simulate()
+        .await
+        .unwrap();
+    assert!(result.value);
+
+    // once validator 0 is unenrolled, its signature no longer counts
+    let _ = instance
+        .methods()
+        .unenroll_validator(TEST_LOCAL_DOMAIN, validators[0].clone())
+        .call()
+        .await;
+    let _ = instance
+        .methods()
+        .enroll_validator(
+            TEST_LOCAL_DOMAIN,
+            ValidatorKey::Fuel(fuel_address(TEST_VALIDATOR_1_PRIVATE_KEY)),
+        )
+        .call()
+        .await;
+
+    let result = instance
+        .methods()
+        .verify(metadata, message_bytes)
+        .simulate()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(
+        decode_revert_reason(result.err().unwrap()).unwrap(),
+        RevertReason::require("!signatures")
+    );
+}
@@ -1,6 +1,19 @@
 contract;
 
-use pause::{interface::Pausable, is_paused, pause, require_unpaused, unpause};
+use ownership::{data_structures::State, only_owner, owner, set_ownership, transfer_ownership};
+use pause::{
+    interface::Pausable,
+    is_paused,
+    only_pauser_or_owner,
+    pause,
+    pauser,
+    remove_pauser,
+    require_unpaused,
+    set_pauser,
+    unpause,
+};
+
+use hyperlane_interfaces::ownable::Ownable;
 
 abi PausableTest {
     #[storage(read)]
@@ -16,13 +29,32 @@ impl Pausable for Contract {
 
     #[storage(read, write)]
     fn pause() {
+        only_pauser_or_owner();
         pause()
     }
 
     #[storage(read, write)]
     fn unpause() {
+        only_owner();
         unpause()
     }
+
+    #[storage(read)]
+    fn pauser() -> Option<Identity> {
+        pauser()
+    }
+
+    #[storage(read, write)]
+    fn set_pauser(pauser: Identity) {
+        only_owner();
+        set_pauser(pauser)
+    }
+
+    #[storage(read, write)]
+    fn remove_pauser() {
+        only_owner();
+        remove_pauser()
+    }
 }
 
 impl PausableTest for Contract {
@@ -31,3 +63,20 @@ impl PausableTest for Contract {
         require_unpaused();
     }
 }
+
+impl Ownable for Contract {
+    #[storage(read)]
+    fn owner() -> State {
+        owner()
+    }
+
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        transfer_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        set_ownership(new_owner);
+    }
+}
@@ -1,5 +1,5 @@
-use fuels::{prelude::*, tx::ContractId};
-use test_utils::get_revert_string;
+use fuels::{prelude::*, tx::ContractId, types::Identity};
+use test_utils::{decode_revert_reason, funded_wallet_with_private_key, RevertReason};
 
 // Load abi from json
 abigen!(Contract(
@@ -7,6 +7,12 @@ abigen!(Contract(
     abi = "contracts/pause-test/out/debug/pause-test-abi.json"
 ));
 
+const PAUSER_PRIVATE_KEY: &str =
+    "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
+
+const NON_OWNER_PRIVATE_KEY: &str =
+    "0x2ef987da35e5b389bb47cc4ec024ce0c37e5defd00de35fe61db6f50d1a858a1";
+
 async fn get_contract_instance() -> (PauseTest<WalletUnlocked>, ContractId) {
     // Launch a local network and deploy the contract
     let mut wallets = launch_custom_provider_and_get_wallets(
@@ -29,8 +35,17 @@ async fn get_contract_instance() -> (PauseTest<WalletUnlocked>, ContractId) {
     .await
     .unwrap();
 
+    let owner_identity = Identity::Address(wallet.address().into());
+
     let instance = PauseTest::new(id.clone(), wallet);
 
+    instance
+        .methods()
+        .set_ownership(owner_identity)
+        .call()
+        .await
+        .unwrap();
+
     (instance, id.into())
 }
 
@@ -71,8 +86,8 @@ async fn test_pause() {
     let second_call = contract.methods().pause().call().await;
     assert!(second_call.is_err());
     assert_eq!(
-        get_revert_string(second_call.err().unwrap()),
-        "contract is already paused"
+        decode_revert_reason(second_call.err().unwrap()).unwrap(),
+        RevertReason::require("contract is already paused")
     );
 }
 
@@ -84,8 +99,8 @@ async fn test_unpause() {
     let call_when_not_paused = contract.methods().unpause().call().await;
     assert!(call_when_not_paused.is_err());
     assert_eq!(
-        get_revert_string(call_when_not_paused.err().unwrap()),
-        "contract is not paused"
+        decode_revert_reason(call_when_not_paused.err().unwrap()).unwrap(),
+        RevertReason::require("contract is not paused")
     );
 
     // Pause
@@ -118,8 +133,8 @@ async fn test_unpause() {
     let second_call = contract.methods().unpause().call().await;
     assert!(second_call.is_err());
     assert_eq!(
-        get_revert_string(second_call.err().unwrap()),
-        "contract is not paused"
+        decode_revert_reason(second_call.err().unwrap()).unwrap(),
+        RevertReason::require("contract is not paused")
     );
 }
 
@@ -138,7 +153,248 @@ async fn test_require_unpaused() {
     let result = contract.methods().require_unpaused().simulate().await;
     assert!(result.is_err());
     assert_eq!(
-        get_revert_string(result.err().unwrap()),
-        "contract is paused"
+        decode_revert_reason(result.err().unwrap()).unwrap(),
+        RevertReason::require("contract is paused")
+    );
+}
+
+#[tokio::test]
+async fn test_initially_no_pauser() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let pauser = contract.methods().pauser().simulate().await.unwrap().value;
+    assert_eq!(pauser, None);
+}
+
+#[tokio::test]
+async fn test_set_pauser() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let pauser_wallet = funded_wallet_with_private_key(&contract.account(), PAUSER_PRIVATE_KEY)
+        .await
+        .unwrap();
+    let pauser_identity = Identity::Address(pauser_wallet.address().into());
+
+    let call = contract
+        .methods()
+        .set_pauser(pauser_identity.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Expect an event
+    let events = call.get_logs_with_type::<PauserSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![PauserSetEvent {
+            pauser: pauser_identity.clone(),
+        }],
+    );
+
+    let pauser = contract.methods().pauser().simulate().await.unwrap().value;
+    assert_eq!(pauser, Some(pauser_identity));
+}
+
+#[tokio::test]
+async fn test_set_pauser_reverts_if_not_owner() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&contract.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+    let non_owner_identity = Identity::Address(non_owner_wallet.address().into());
+
+    let call = contract
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_pauser(non_owner_identity)
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_remove_pauser() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let pauser_wallet = funded_wallet_with_private_key(&contract.account(), PAUSER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    contract
+        .methods()
+        .set_pauser(Identity::Address(pauser_wallet.address().into()))
+        .call()
+        .await
+        .unwrap();
+
+    let call = contract.methods().remove_pauser().call().await.unwrap();
+
+    // Expect an event
+    let events = call.get_logs_with_type::<PauserRemovedEvent>().unwrap();
+    assert_eq!(events, vec![PauserRemovedEvent {}]);
+
+    let pauser = contract.methods().pauser().simulate().await.unwrap().value;
+    assert_eq!(pauser, None);
+
+    // The removed pauser can no longer pause
+    let call = contract
+        .with_account(pauser_wallet)
+        .unwrap()
+        .methods()
+        .pause()
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_remove_pauser_reverts_if_not_set() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let call = contract.methods().remove_pauser().call().await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("pauser not set")
+    );
+}
+
+#[tokio::test]
+async fn test_remove_pauser_reverts_if_not_owner() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let pauser_wallet = funded_wallet_with_private_key(&contract.account(), PAUSER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    contract
+        .methods()
+        .set_pauser(Identity::Address(pauser_wallet.address().into()))
+        .call()
+        .await
+        .unwrap();
+
+    // Not even the pauser can remove itself
+    let call = contract
+        .with_account(pauser_wallet)
+        .unwrap()
+        .methods()
+        .remove_pauser()
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_pauser_can_pause_but_not_unpause() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let pauser_wallet = funded_wallet_with_private_key(&contract.account(), PAUSER_PRIVATE_KEY)
+        .await
+        .unwrap();
+    let pauser_identity = Identity::Address(pauser_wallet.address().into());
+
+    contract
+        .methods()
+        .set_pauser(pauser_identity)
+        .call()
+        .await
+        .unwrap();
+
+    let pauser_contract = contract.with_account(pauser_wallet).unwrap();
+
+    // The pauser can pause
+    pauser_contract.methods().pause().call().await.unwrap();
+
+    let paused = contract
+        .methods()
+        .is_paused()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(paused);
+
+    // But the pauser can't unpause
+    let call = pauser_contract.methods().unpause().call().await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+
+    // The owner still can
+    contract.methods().unpause().call().await.unwrap();
+
+    let paused = contract
+        .methods()
+        .is_paused()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(!paused);
+}
+
+#[tokio::test]
+async fn test_pause_reverts_if_not_pauser_or_owner() {
+    let (contract, _id) = get_contract_instance().await;
+
+    let pauser_wallet = funded_wallet_with_private_key(&contract.account(), PAUSER_PRIVATE_KEY)
+        .await
+        .unwrap();
+    let non_owner_wallet =
+        funded_wallet_with_private_key(&contract.account(), NON_OWNER_PRIVATE_KEY)
+            .await
+            .unwrap();
+
+    // Without a pauser set, only the owner can pause
+    let call = contract
+        .with_account(non_owner_wallet.clone())
+        .unwrap()
+        .methods()
+        .pause()
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+
+    contract
+        .methods()
+        .set_pauser(Identity::Address(pauser_wallet.address().into()))
+        .call()
+        .await
+        .unwrap();
+
+    // With a pauser set, identities that are neither the pauser nor the owner still can't pause
+    let call = contract
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .pause()
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
     );
 }
@@ -4,6 +4,14 @@ pub struct PausedEvent {}
 
 pub struct UnpausedEvent {}
 
+/// Logged when the pauser is set.
+pub struct PauserSetEvent {
+    pauser: Identity,
+}
+
+/// Logged when the pauser is removed.
+pub struct PauserRemovedEvent {}
+
 abi Pausable {
     #[storage(read)]
     fn is_paused() -> bool;
@@ -13,4 +21,13 @@ abi Pausable {
 
     #[storage(read, write)]
     fn unpause();
+
+    #[storage(read)]
+    fn pauser() -> Option<Identity>;
+
+    #[storage(read, write)]
+    fn set_pauser(pauser: Identity);
+
+    #[storage(read, write)]
+    fn remove_pauser();
 }
@@ -3,10 +3,12 @@ library;
 mod interface;
 mod r#storage;
 
-use std::{logging::log, storage::{get, store}};
+use std::{auth::msg_sender, logging::log, storage::{clear, get, store}};
 
-use interface::{PausedEvent, UnpausedEvent};
-use storage::PAUSED_STORAGE_KEY;
+use ownership::only_owner;
+
+use interface::{PausedEvent, PauserRemovedEvent, PauserSetEvent, UnpausedEvent};
+use storage::{PAUSED_STORAGE_KEY, PAUSER_STORAGE_KEY};
 
 #[storage(read)]
 pub fn require_unpaused() {
@@ -36,3 +38,43 @@ pub fn unpause() {
     store(PAUSED_STORAGE_KEY, false);
     log(UnpausedEvent {})
 }
+
+/// Returns the pauser, or None if no pauser has been set.
+#[storage(read)]
+pub fn pauser() -> Option<Identity> {
+    get(PAUSER_STORAGE_KEY)
+}
+
+/// Sets the pauser, an identity that is permitted to pause but not unpause.
+/// Access control is left to the caller, which is expected to restrict
+/// this to the owner.
+#[storage(write)]
+pub fn set_pauser(pauser: Identity) {
+    store(PAUSER_STORAGE_KEY, pauser);
+    log(PauserSetEvent { pauser })
+}
+
+/// Removes the pauser, so that only the owner may pause.
+/// Reverts if no pauser has been set. Access control is left to the caller,
+/// which is expected to restrict this to the owner.
+#[storage(read, write)]
+pub fn remove_pauser() {
+    require(pauser().is_some(), "pauser not set");
+    let _ = clear::<Identity>(PAUSER_STORAGE_KEY);
+    log(PauserRemovedEvent {})
+}
+
+/// Reverts if the msg_sender is neither the pauser nor the owner.
+#[storage(read)]
+pub fn only_pauser_or_owner() {
+    let sender = msg_sender().unwrap();
+    match pauser() {
+        Option::Some(pauser) => {
+            if pauser == sender {
+                return;
+            }
+        },
+        Option::None => {},
+    }
+    only_owner();
+}
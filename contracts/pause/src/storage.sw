@@ -8,3 +8,7 @@ library;
 // $ cast keccak 'paused_storage_key'
 // 0x5b57966e8321b6512a51b1b5b3cbe383bfe5d5045fdaae961f003a190c66e025
 pub const PAUSED_STORAGE_KEY: b256 = 0x5b57966e8321b6512a51b1b5b3cbe383bfe5d5045fdaae961f003a190c66e025;
+
+// $ cast keccak 'pauser_storage_key'
+// 0x61b467b5ceb80ac5086f0f18729b1ef10d8f9660301f640c8bc19a768c386b88
+pub const PAUSER_STORAGE_KEY: b256 = 0x61b467b5ceb80ac5086f0f18729b1ef10d8f9660301f640c8bc19a768c386b88;
@@ -0,0 +1,18 @@
+library;
+
+/// Logged when the implementation contract is set.
+pub struct ImplementationSetEvent {
+    implementation: ContractId,
+}
+
+/// A proxy that delegates all unknown calls to an implementation contract.
+abi Proxy {
+    /// Gets the implementation contract ID.
+    /// Reverts if no implementation has been set.
+    #[storage(read)]
+    fn implementation() -> ContractId;
+
+    /// Sets the implementation contract ID. Only callable by the owner.
+    #[storage(read, write)]
+    fn set_implementation(implementation: ContractId);
+}
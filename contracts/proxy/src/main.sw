@@ -0,0 +1,91 @@
+contract;
+
+mod interface;
+
+use std::{execution::run_external, logging::log, storage::{get, store}};
+
+use std_lib_extended::option::*;
+
+use ownership::{data_structures::State, only_owner, owner, set_ownership, transfer_ownership};
+
+use hyperlane_interfaces::ownable::Ownable;
+
+use interface::{ImplementationSetEvent, Proxy};
+
+// The proxy executes the implementation's code within its own context, so the
+// implementation reads and writes the proxy's storage. To avoid colliding with
+// the implementation's `storage` block, the proxy doesn't declare any storage of
+// its own and instead uses a manually chosen storage key.
+//
+// In the same spirit as the `pause` library, the storage key is arbitrarily chosen
+// as the keccak of "proxy_implementation_storage_key":
+// $ cast keccak 'proxy_implementation_storage_key'
+// 0xef46737da2a63b97f2257ecc87f7d07a2e2aec34984432ffc645bcd17e79dd66
+const IMPLEMENTATION_STORAGE_KEY: b256 = 0xef46737da2a63b97f2257ecc87f7d07a2e2aec34984432ffc645bcd17e79dd66;
+
+/// An upgradable proxy. All calls to functions that aren't a part of the
+/// `Proxy` or `Ownable` ABIs are forwarded to the implementation contract,
+/// whose code is loaded and ran in the context of this contract.
+///
+/// Ownership is stored using the `ownership` library's storage key, which is
+/// shared with any implementation that also uses the `ownership` library (e.g.
+/// the Mailbox). The owner of the implementation is therefore also the owner
+/// of the proxy, and is the only identity able to upgrade it.
+///
+/// Implementations must preserve the storage layout of prior implementations.
+/// Because storage keys are derived from the order of fields in a `storage`
+/// block, new fields may only be appended, and existing fields must never be
+/// reordered, removed, or have their types changed.
+impl Proxy for Contract {
+    /// Gets the implementation contract ID.
+    /// Reverts if no implementation has been set.
+    #[storage(read)]
+    fn implementation() -> ContractId {
+        implementation()
+    }
+
+    /// Sets the implementation contract ID. Only callable by the owner.
+    #[storage(read, write)]
+    fn set_implementation(implementation: ContractId) {
+        only_owner();
+
+        store(IMPLEMENTATION_STORAGE_KEY, implementation);
+        log(ImplementationSetEvent { implementation });
+    }
+}
+
+impl Ownable for Contract {
+    /// Gets the current owner.
+    #[storage(read)]
+    fn owner() -> State {
+        owner()
+    }
+
+    /// Transfers ownership to `new_owner`.
+    /// Reverts if the msg_sender is not the current owner.
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        transfer_ownership(new_owner);
+    }
+
+    /// Initializes ownership to `new_owner`.
+    /// Reverts if owner already initialized.
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        set_ownership(new_owner);
+    }
+}
+
+/// Forwards any call that doesn't match a function in this contract's
+/// ABIs to the implementation.
+#[fallback, storage(read)]
+fn fallback() {
+    run_external(implementation())
+}
+
+/// Gets the implementation contract ID.
+/// Reverts if no implementation has been set.
+#[storage(read)]
+fn implementation() -> ContractId {
+    get::<ContractId>(IMPLEMENTATION_STORAGE_KEY).expect("no implementation set")
+}
@@ -0,0 +1,435 @@
+use ethers::types::H256;
+use fuels::{
+    prelude::*,
+    tx::{ContractId, Salt},
+    types::{Bits256, Bytes, Identity},
+};
+use hyperlane_core::{Encode, HyperlaneMessage as HyperlaneAgentMessage};
+use test_utils::{
+    decode_revert_reason, funded_wallet_with_private_key, h256_to_bits256, RevertReason,
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "Proxy",
+    abi = "contracts/proxy/out/debug/proxy-abi.json"
+));
+
+mod mailbox_contract {
+    use fuels::prelude::abigen;
+
+    // Load abi from json
+    abigen!(Contract(
+        name = "Mailbox",
+        abi = "contracts/hyperlane-mailbox/out/debug/hyperlane-mailbox-abi.json"
+    ));
+}
+
+use crate::mailbox_contract::Mailbox;
+
+const NON_OWNER_PRIVATE_KEY: &str =
+    "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
+
+const TEST_LOCAL_DOMAIN: u32 = 0x6675656cu32;
+const TEST_REMOTE_DOMAIN: u32 = 0x112233cu32;
+const TEST_RECIPIENT: &str = "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+struct TestContracts {
+    proxy: Proxy<WalletUnlocked>,
+    /// A Mailbox instance pointed at the proxy's contract ID.
+    proxied_mailbox: Mailbox<WalletUnlocked>,
+    /// Two Mailbox implementations with identical bytecode and different salts.
+    implementations: (Bech32ContractId, Bech32ContractId),
+    ism_id: Bech32ContractId,
+    recipient_id: Bech32ContractId,
+}
+
+async fn deploy_mailbox_implementation(
+    wallet: &WalletUnlocked,
+    salt: [u8; 32],
+) -> Bech32ContractId {
+    let mailbox_configurables =
+        mailbox_contract::MailboxConfigurables::new().set_LOCAL_DOMAIN(TEST_LOCAL_DOMAIN);
+
+    Contract::deploy(
+        "../hyperlane-mailbox/out/debug/hyperlane-mailbox.bin",
+        wallet,
+        DeployConfiguration::default()
+            .set_storage_configuration(StorageConfiguration::new(
+                "../hyperlane-mailbox/out/debug/hyperlane-mailbox-storage_slots.json".to_string(),
+                vec![],
+            ))
+            .set_configurables(mailbox_configurables)
+            .set_salt(Salt::from(salt)),
+    )
+    .await
+    .unwrap()
+}
+
+async fn get_contract_instances() -> TestContracts {
+    // Launch a local network and deploy the contract
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(
+            Some(1),             /* Single wallet */
+            Some(1),             /* Single coin (UTXO) */
+            Some(1_000_000_000), /* Amount per coin */
+        ),
+        None,
+        None,
+    )
+    .await;
+    let wallet = wallets.pop().unwrap();
+
+    let implementations = (
+        deploy_mailbox_implementation(&wallet, [0u8; 32]).await,
+        deploy_mailbox_implementation(&wallet, [1u8; 32]).await,
+    );
+
+    let proxy_id = Contract::deploy(
+        "./out/debug/proxy.bin",
+        &wallet,
+        DeployConfiguration::default().set_storage_configuration(StorageConfiguration::new(
+            "./out/debug/proxy-storage_slots.json".to_string(),
+            vec![],
+        )),
+    )
+    .await
+    .unwrap();
+
+    let proxy = Proxy::new(proxy_id.clone(), wallet.clone());
+
+    let owner_identity = Identity::Address(wallet.address().into());
+    proxy
+        .methods()
+        .set_ownership(owner_identity)
+        .call()
+        .await
+        .unwrap();
+
+    proxy
+        .methods()
+        .set_implementation(implementations.0.clone().into())
+        .call()
+        .await
+        .unwrap();
+
+    let ism_id = Contract::deploy(
+        "../hyperlane-ism-test/out/debug/hyperlane-ism-test.bin",
+        &wallet,
+        DeployConfiguration::default().set_storage_configuration(StorageConfiguration::new(
+            "../hyperlane-ism-test/out/debug/hyperlane-ism-test-storage_slots.json".to_string(),
+            vec![],
+        )),
+    )
+    .await
+    .unwrap();
+
+    let recipient_id = Contract::deploy(
+        "../hyperlane-msg-recipient-test/out/debug/hyperlane-msg-recipient-test.bin",
+        &wallet,
+        DeployConfiguration::default()
+        .set_storage_configuration(
+        StorageConfiguration::new(
+            "../hyperlane-msg-recipient-test/out/debug/hyperlane-msg-recipient-test-storage_slots.json".to_string(),
+            vec![])),
+    )
+    .await
+    .unwrap();
+
+    let proxied_mailbox = Mailbox::new(proxy_id, wallet);
+
+    TestContracts {
+        proxy,
+        proxied_mailbox,
+        implementations,
+        ism_id,
+        recipient_id,
+    }
+}
+
+// An inbound test message to be processed by the proxied Mailbox.
+fn test_inbound_message(
+    sender: &WalletUnlocked,
+    recipient: &Bech32ContractId,
+) -> HyperlaneAgentMessage {
+    let sender: Address = sender.address().into();
+    HyperlaneAgentMessage {
+        version: 0u8,
+        nonce: 0u32,
+        origin: TEST_REMOTE_DOMAIN,
+        sender: H256::from(*sender),
+        destination: TEST_LOCAL_DOMAIN,
+        recipient: H256::from(*recipient.hash()),
+        body: vec![10u8; 100],
+    }
+}
+
+#[tokio::test]
+async fn test_implementation_set() {
+    let TestContracts {
+        proxy,
+        implementations,
+        ..
+    } = get_contract_instances().await;
+
+    let implementation = proxy
+        .methods()
+        .implementation()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(implementation, implementations.0.into());
+}
+
+#[tokio::test]
+async fn test_set_implementation() {
+    let TestContracts {
+        proxy,
+        implementations,
+        ..
+    } = get_contract_instances().await;
+
+    let new_implementation: ContractId = implementations.1.into();
+
+    let call = proxy
+        .methods()
+        .set_implementation(new_implementation)
+        .call()
+        .await
+        .unwrap();
+
+    let events = call.get_logs_with_type::<ImplementationSetEvent>().unwrap();
+    assert_eq!(
+        events,
+        vec![ImplementationSetEvent {
+            implementation: new_implementation,
+        }]
+    );
+
+    let implementation = proxy
+        .methods()
+        .implementation()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(implementation, new_implementation);
+}
+
+#[tokio::test]
+async fn test_set_implementation_reverts_if_not_owner() {
+    let TestContracts {
+        proxy,
+        implementations,
+        ..
+    } = get_contract_instances().await;
+
+    let non_owner_wallet = funded_wallet_with_private_key(&proxy.account(), NON_OWNER_PRIVATE_KEY)
+        .await
+        .unwrap();
+
+    let call = proxy
+        .with_account(non_owner_wallet)
+        .unwrap()
+        .methods()
+        .set_implementation(implementations.1.into())
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("NotOwner")
+    );
+}
+
+#[tokio::test]
+async fn test_fallback_reverts_if_no_implementation_set() {
+    let TestContracts {
+        proxied_mailbox, ..
+    } = get_contract_instances().await;
+
+    // Deploy a fresh proxy without setting an implementation
+    let wallet = proxied_mailbox.account();
+    let proxy_id = Contract::deploy(
+        "./out/debug/proxy.bin",
+        &wallet,
+        DeployConfiguration::default()
+            .set_storage_configuration(StorageConfiguration::new(
+                "./out/debug/proxy-storage_slots.json".to_string(),
+                vec![],
+            ))
+            .set_salt(Salt::from([2u8; 32])),
+    )
+    .await
+    .unwrap();
+
+    let call = Mailbox::new(proxy_id, wallet)
+        .methods()
+        .count()
+        .simulate()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("no implementation set")
+    );
+}
+
+#[tokio::test]
+async fn test_proxied_mailbox_shares_owner() {
+    let TestContracts {
+        proxy,
+        proxied_mailbox,
+        implementations,
+        ..
+    } = get_contract_instances().await;
+
+    let owner_identity = Identity::Address(proxy.account().address().into());
+
+    // Ownership set on the proxy is visible through the proxied Mailbox,
+    // so the owner can call `only_owner` Mailbox functions through the proxy.
+    let proxy_owner = proxy.methods().owner().simulate().await.unwrap().value;
+    assert_eq!(proxy_owner, State::Initialized(owner_identity.clone()));
+
+    let mailbox_owner = proxied_mailbox
+        .methods()
+        .owner()
+        .set_contract_ids(&[implementations.0.clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        mailbox_owner,
+        mailbox_contract::State::Initialized(owner_identity)
+    );
+
+    let call = proxied_mailbox
+        .methods()
+        .pause()
+        .set_contract_ids(&[implementations.0])
+        .call()
+        .await;
+    assert!(call.is_ok());
+}
+
+#[tokio::test]
+async fn test_state_survives_upgrade() {
+    let TestContracts {
+        proxy,
+        proxied_mailbox,
+        implementations,
+        ism_id,
+        recipient_id,
+    } = get_contract_instances().await;
+
+    let raw_ism_id: ContractId = ism_id.clone().into();
+    proxied_mailbox
+        .methods()
+        .set_default_ism(raw_ism_id)
+        .set_contract_ids(&[implementations.0.clone()])
+        .call()
+        .await
+        .unwrap();
+
+    // Dispatch a couple messages to grow the merkle tree
+    for _ in 0..2 {
+        proxied_mailbox
+            .methods()
+            .dispatch(
+                TEST_REMOTE_DOMAIN,
+                Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+                Bytes(vec![10u8; 100]),
+            )
+            .set_contract_ids(&[implementations.0.clone()])
+            .call()
+            .await
+            .unwrap();
+    }
+
+    // Process an inbound message
+    let inbound_message = test_inbound_message(&proxied_mailbox.account(), &recipient_id);
+    proxied_mailbox
+        .methods()
+        .process(Bytes(vec![5u8; 100]), Bytes(inbound_message.to_vec()))
+        .set_contract_ids(&[implementations.0.clone(), ism_id, recipient_id])
+        .tx_params(TxParameters::default().set_gas_limit(1_200_000))
+        .call()
+        .await
+        .unwrap();
+
+    let (root_before, index_before) = proxied_mailbox
+        .methods()
+        .latest_checkpoint()
+        .set_contract_ids(&[implementations.0.clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(index_before, 1u32);
+
+    // Upgrade to the second implementation
+    proxy
+        .methods()
+        .set_implementation(implementations.1.clone().into())
+        .call()
+        .await
+        .unwrap();
+
+    // The merkle tree is unchanged
+    let (root_after, index_after) = proxied_mailbox
+        .methods()
+        .latest_checkpoint()
+        .set_contract_ids(&[implementations.1.clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(root_after, root_before);
+    assert_eq!(index_after, index_before);
+
+    // The delivered map is unchanged
+    let delivered = proxied_mailbox
+        .methods()
+        .delivered(h256_to_bits256(inbound_message.id()))
+        .set_contract_ids(&[implementations.1.clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(delivered);
+
+    // The default ISM is unchanged
+    let default_ism = proxied_mailbox
+        .methods()
+        .get_default_ism()
+        .set_contract_ids(&[implementations.1.clone()])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(default_ism, raw_ism_id);
+
+    // And dispatching continues from the existing tree
+    proxied_mailbox
+        .methods()
+        .dispatch(
+            TEST_REMOTE_DOMAIN,
+            Bits256::from_hex_str(TEST_RECIPIENT).unwrap(),
+            Bytes(vec![10u8; 100]),
+        )
+        .set_contract_ids(&[implementations.1.clone()])
+        .call()
+        .await
+        .unwrap();
+    let count = proxied_mailbox
+        .methods()
+        .count()
+        .set_contract_ids(&[implementations.1])
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(count, 3u32);
+}
@@ -1,9 +1,11 @@
 library;
 
-use std::{bytes::Bytes, vm::evm::evm_address::EvmAddress};
+use std::{bytes::Bytes, constants::ZERO_B256, vm::evm::evm_address::EvmAddress};
 
 use std_lib_extended::bytes::*;
 
+use hyperlane_interfaces::validator_key::ValidatorKey;
+
 pub struct ValidatorAnnounceDigest {
     bytes: Bytes,
 }
@@ -18,44 +20,93 @@ pub struct ValidatorAnnounceDigest {
 /// ))
 /// ```
 /// Found here: https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/0b60a32e9cf0fc98c203379b6100b6b9aa61dac9/solidity/contracts/libs/ValidatorAnnouncements.sol#L23-L29
+///
+/// Revocations use the same domain hash with the suffix "HYPERLANE_REVOCATION",
+/// so an announcement signature can never be used as a revocation signature.
+///
+/// Fuel validators append "_FUEL" to the suffix, so a signature for an EVM
+/// validator can't be replayed for the Fuel validator of the same key.
 const DOMAIN_HASH_LOCAL_DOMAIN_OFFSET: u64 = 0;
 const DOMAIN_HASH_MAILBOX_ID_OFFSET: u64 = 4;
-// The suffix is "HYPERLANE_ANNOUNCEMENT"
 const DOMAIN_HASH_SUFFIX_OFFSET: u64 = 36;
-const DOMAIN_HASH_SUFFIX_LEN: u64 = 22;
-// The length is DOMAIN_HASH_SUFFIX_OFFSET + DOMAIN_HASH_SUFFIX_LEN
-const DOMAIN_HASH_LEN: u64 = 58;
-
-fn domain_hash(mailbox_id: b256, local_domain: u32) -> b256 {
-    let mut bytes = Bytes::with_length(DOMAIN_HASH_LEN);
+// The length of "HYPERLANE_ANNOUNCEMENT"
+const ANNOUNCEMENT_SUFFIX_LEN: u64 = 22;
+// The length of "HYPERLANE_ANNOUNCEMENT_FUEL"
+const FUEL_ANNOUNCEMENT_SUFFIX_LEN: u64 = 27;
+// The length of "HYPERLANE_REVOCATION"
+const REVOCATION_SUFFIX_LEN: u64 = 20;
+// The length of "HYPERLANE_REVOCATION_FUEL"
+const FUEL_REVOCATION_SUFFIX_LEN: u64 = 25;
+
+fn domain_hash(
+    mailbox_id: b256,
+    local_domain: u32,
+    suffix_ptr: raw_ptr,
+    suffix_len: u64,
+) -> b256 {
+    let mut bytes = Bytes::with_length(DOMAIN_HASH_SUFFIX_OFFSET + suffix_len);
 
     let _ = bytes.write_u32(DOMAIN_HASH_LOCAL_DOMAIN_OFFSET, local_domain);
     let _ = bytes.write_b256(DOMAIN_HASH_MAILBOX_ID_OFFSET, mailbox_id);
-
-    let suffix: str[22] = "HYPERLANE_ANNOUNCEMENT";
-    let _ = bytes.write_packed_bytes(DOMAIN_HASH_SUFFIX_OFFSET, __addr_of(suffix), DOMAIN_HASH_SUFFIX_LEN);
+    let _ = bytes.write_packed_bytes(DOMAIN_HASH_SUFFIX_OFFSET, suffix_ptr, suffix_len);
 
     bytes.keccak256()
 }
 
 /// The announcement digest.
-/// Equivalent of Solidity's:
+/// For EVM validators, the equivalent of Solidity's:
 /// ```
 /// ECDSA.toEthSignedMessageHash(
 ///     keccak256(abi.encodePacked(_domainHash, _storageLocation))
 /// );
 /// ```
 /// Found here: https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/0b60a32e9cf0fc98c203379b6100b6b9aa61dac9/solidity/contracts/libs/ValidatorAnnouncements.sol#L30-L33
+///
+/// Fuel validators sign the same message, but with the Fuel domain hash.
 const DIGEST_DOMAIN_HASH_OFFSET: u64 = 0;
 const DIGEST_STORAGE_LOCATION_OFFSET: u64 = 32;
 
 pub fn get_announcement_digest(
     mailbox_id: b256,
     local_domain: u32,
+    validator: ValidatorKey,
+    storage_location: Bytes,
+) -> b256 {
+    let domain_hash = match validator {
+        ValidatorKey::Evm(_) => {
+            let suffix: str[22] = "HYPERLANE_ANNOUNCEMENT";
+            domain_hash(mailbox_id, local_domain, __addr_of(suffix), ANNOUNCEMENT_SUFFIX_LEN)
+        },
+        ValidatorKey::Fuel(_) => {
+            let suffix: str[27] = "HYPERLANE_ANNOUNCEMENT_FUEL";
+            domain_hash(mailbox_id, local_domain, __addr_of(suffix), FUEL_ANNOUNCEMENT_SUFFIX_LEN)
+        },
+    };
+    get_storage_location_digest(domain_hash, storage_location)
+}
+
+/// The revocation digest. The same as the announcement digest, but using
+/// the revocation domain hash.
+pub fn get_revocation_digest(
+    mailbox_id: b256,
+    local_domain: u32,
+    validator: ValidatorKey,
     storage_location: Bytes,
 ) -> b256 {
-    let domain_hash = domain_hash(mailbox_id, local_domain);
+    let domain_hash = match validator {
+        ValidatorKey::Evm(_) => {
+            let suffix: str[20] = "HYPERLANE_REVOCATION";
+            domain_hash(mailbox_id, local_domain, __addr_of(suffix), REVOCATION_SUFFIX_LEN)
+        },
+        ValidatorKey::Fuel(_) => {
+            let suffix: str[25] = "HYPERLANE_REVOCATION_FUEL";
+            domain_hash(mailbox_id, local_domain, __addr_of(suffix), FUEL_REVOCATION_SUFFIX_LEN)
+        },
+    };
+    get_storage_location_digest(domain_hash, storage_location)
+}
 
+fn get_storage_location_digest(domain_hash: b256, storage_location: Bytes) -> b256 {
     let len = DIGEST_STORAGE_LOCATION_OFFSET + storage_location.len();
 
     let mut signed_message_payload = Bytes::with_length(len);
@@ -69,20 +120,56 @@ pub fn get_announcement_digest(
 }
 
 /// The replay ID.
-/// Equivalent of Solidity's:
+/// For EVM validators, the equivalent of Solidity's:
 /// ```
 /// keccak256(abi.encodePacked(_validator, _storageLocation))
 /// ```
 /// Found here: https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/0b60a32e9cf0fc98c203379b6100b6b9aa61dac9/solidity/contracts/ValidatorAnnounce.sol#L75-L77
+///
+/// Fuel validators are encoded the same way, but with their 32 byte address.
 const REPLAY_ID_VALIDATOR_OFFSET: u64 = 0;
-const REPLAY_ID_STORAGE_LOCATION_OFFSET: u64 = 20;
+const EVM_ADDRESS_LEN: u64 = 20;
+const FUEL_ADDRESS_LEN: u64 = 32;
+
+pub fn get_replay_id(validator: ValidatorKey, storage_location: Bytes) -> b256 {
+    let validator_len = match validator {
+        ValidatorKey::Evm(_) => EVM_ADDRESS_LEN,
+        ValidatorKey::Fuel(_) => FUEL_ADDRESS_LEN,
+    };
+
+    let mut bytes = Bytes::with_length(validator_len + storage_location.len());
+    let _ = match validator {
+        ValidatorKey::Evm(address) => bytes.write_evm_address(REPLAY_ID_VALIDATOR_OFFSET, address),
+        ValidatorKey::Fuel(address) => bytes.write_b256(REPLAY_ID_VALIDATOR_OFFSET, address.into()),
+    };
+    let _ = bytes.write_bytes(validator_len, storage_location);
 
-pub fn get_replay_id(validator: EvmAddress, storage_location: Bytes) -> b256 {
-    let len = REPLAY_ID_STORAGE_LOCATION_OFFSET + storage_location.len();
+    bytes.keccak256()
+}
 
-    let mut bytes = Bytes::with_length(len);
-    let _ = bytes.write_evm_address(REPLAY_ID_VALIDATOR_OFFSET, validator);
-    let _ = bytes.write_bytes(REPLAY_ID_STORAGE_LOCATION_OFFSET, storage_location);
+#[test()]
+fn test_get_announcement_digest() {
+    // The same vector is used by `announcement_digest` in test-utils.
+    let storage_location: str[27] = "file://some/path/to/storage";
+    let mut storage_location_bytes = Bytes::with_length(27);
+    let _ = storage_location_bytes.write_packed_bytes(0u64, __addr_of(storage_location), 27);
 
-    bytes.keccak256()
+    let validator = ValidatorKey::Evm(EvmAddress::from(ZERO_B256));
+    let digest = get_announcement_digest(0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe, 0x6675656cu32, validator, storage_location_bytes);
+    assert(digest == 0x9923e6d2c24809fd881d27d2e81745cd3ea397969cc6bef1fdc613e0a5bb42f0);
+}
+
+#[test()]
+fn test_digests_differ_by_validator_key_type() {
+    let storage_location: str[27] = "file://some/path/to/storage";
+    let mut storage_location_bytes = Bytes::with_length(27);
+    let _ = storage_location_bytes.write_packed_bytes(0u64, __addr_of(storage_location), 27);
+
+    let mailbox_id = 0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe;
+    let local_domain = 0x6675656cu32;
+    let evm = ValidatorKey::Evm(EvmAddress::from(ZERO_B256));
+    let fuel = ValidatorKey::Fuel(Address::from(ZERO_B256));
+
+    assert(get_announcement_digest(mailbox_id, local_domain, evm, storage_location_bytes) != get_announcement_digest(mailbox_id, local_domain, fuel, storage_location_bytes));
+    assert(get_revocation_digest(mailbox_id, local_domain, evm, storage_location_bytes) != get_revocation_digest(mailbox_id, local_domain, fuel, storage_location_bytes));
 }
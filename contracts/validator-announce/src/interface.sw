@@ -1,31 +1,57 @@
 library;
 
-use std::{b512::B512, bytes::Bytes, vm::evm::evm_address::EvmAddress};
+use std::{b512::B512, bytes::Bytes};
+
+use hyperlane_interfaces::validator_key::ValidatorKey;
 
 use ::storable_string::StorableString;
 
 /// Logged when a validator announcement is made.
 pub struct ValidatorAnnouncementEvent {
-    validator: EvmAddress,
+    validator: ValidatorKey,
+    storage_location: StorableString,
+}
+
+/// Logged when a validator revokes a previously announced storage location.
+pub struct ValidatorRevocationEvent {
+    validator: ValidatorKey,
     storage_location: StorableString,
 }
 
 abi ValidatorAnnounce {
     #[storage(read, write)]
-    fn announce_vec(validator: EvmAddress, storage_location_vec: Vec<u8>, signature: B512);
+    fn announce_vec(validator: ValidatorKey, storage_location_vec: Vec<u8>, signature: B512);
+
+    #[storage(read, write)]
+    fn announce(validator: ValidatorKey, storage_location: Bytes, signature: B512);
+
+    #[storage(read, write)]
+    fn revoke_vec(validator: ValidatorKey, storage_location_vec: Vec<u8>, signature: B512);
 
     #[storage(read, write)]
-    fn announce(validator: EvmAddress, storage_location: Bytes, signature: B512);
+    fn revoke(validator: ValidatorKey, storage_location: Bytes, signature: B512);
+
+    #[storage(read)]
+    fn get_announced_storage_locations(validators: Vec<ValidatorKey>, include_revoked: bool) -> Vec<Vec<Bytes>>;
+
+    #[storage(read)]
+    fn get_announced_storage_location(validator: ValidatorKey, storage_location_index: Option<u64>, include_revoked: bool) -> Bytes;
+
+    #[storage(read)]
+    fn is_storage_location_revoked(validator: ValidatorKey, storage_location_index: u64) -> bool;
+
+    #[storage(read)]
+    fn get_announced_storage_location_count(validator: ValidatorKey) -> u64;
 
     #[storage(read)]
-    fn get_announced_storage_locations(validators: Vec<EvmAddress>) -> Vec<Vec<Bytes>>;
+    fn get_announced_storage_locations_paginated(validator: ValidatorKey, offset: u64, limit: u64, include_revoked: bool) -> Vec<StorableString>;
 
     #[storage(read)]
-    fn get_announced_storage_location(validator: EvmAddress, storage_location_index: Option<u64>) -> Bytes;
+    fn get_announced_validators() -> Vec<ValidatorKey>;
 
     #[storage(read)]
-    fn get_announced_storage_location_count(validator: EvmAddress) -> u64;
+    fn get_announced_validator_count() -> u64;
 
     #[storage(read)]
-    fn get_announced_validators() -> Vec<EvmAddress>;
+    fn get_announced_validators_paginated(offset: u64, limit: u64) -> Vec<ValidatorKey>;
 }
@@ -4,21 +4,15 @@ mod digest;
 mod storable_string;
 mod interface;
 
-use std::{
-    b512::B512,
-    bytes::Bytes,
-    storage::StorageVec,
-    vm::evm::{
-        ecr::ec_recover_evm_address,
-        evm_address::EvmAddress,
-    },
-};
+use std::{b512::B512, bytes::Bytes, storage::StorageVec};
 
 use storagemapvec::StorageMapVec;
 
-use digest::{get_announcement_digest, get_replay_id};
-use interface::{ValidatorAnnounce, ValidatorAnnouncementEvent};
-use storable_string::{MAX_STORABLE_STRING_CHARS, StorableString};
+use hyperlane_interfaces::validator_key::{Signer, ValidatorKey};
+
+use digest::{get_announcement_digest, get_replay_id, get_revocation_digest};
+use interface::{ValidatorAnnounce, ValidatorAnnouncementEvent, ValidatorRevocationEvent};
+use storable_string::{chunk_count, get_chunk, MAX_STORABLE_STRING_CHARS, StorableString, write_chunk};
 
 use std_lib_extended::{bytes::*, option::*, result::*};
 
@@ -35,11 +29,17 @@ storage {
     /// Used for ensuring a storage location for a validator cannot be announced more than once.
     replay_protection: StorageMap<b256, bool> = StorageMap {},
     /// Lookup table for whether a validator has made any announcements.
-    validators_map: StorageMap<EvmAddress, bool> = StorageMap {},
+    validators_map: StorageMap<ValidatorKey, bool> = StorageMap {},
     /// Unique validators that have made announcements.
-    validators_vec: StorageVec<EvmAddress> = StorageVec {},
-    /// Storage locations announced by each validator.
-    storage_locations: StorageMapVec<EvmAddress, StorableString> = StorageMapVec {},
+    validators_vec: StorageVec<ValidatorKey> = StorageVec {},
+    /// The lengths of the storage locations announced by each validator.
+    storage_locations: StorageMapVec<ValidatorKey, u64> = StorageMapVec {},
+    /// (Validator, storage location index, chunk index) -> chunk of the storage location.
+    storage_location_chunks: StorageMap<(ValidatorKey, u64, u64), str[128]> = StorageMap {},
+    /// Replay id -> the index of the storage location among the validator's storage locations.
+    storage_location_indices: StorageMap<b256, u64> = StorageMap {},
+    /// (Validator, storage location index) -> whether the storage location has been revoked.
+    revoked_storage_locations: StorageMap<(ValidatorKey, u64), bool> = StorageMap {},
 }
 
 /// Bytes are intentionally used instead of the sway-lib String throughout this contract
@@ -55,7 +55,7 @@ impl ValidatorAnnounce for Contract {
     /// behind the scenes.
     #[storage(read, write)]
     fn announce_vec(
-        validator: EvmAddress,
+        validator: ValidatorKey,
         storage_location_vec: Vec<u8>,
         signature: B512,
     ) {
@@ -65,19 +65,45 @@ impl ValidatorAnnounce for Contract {
     /// Announces a validator's storage location.
     #[storage(read, write)]
     fn announce(
-        validator: EvmAddress,
+        validator: ValidatorKey,
         storage_location: Bytes,
         signature: B512,
     ) {
         announce(validator, storage_location, signature);
     }
 
-    /// Returns all announced storage locations for each of the validators.
+    /// TODO: remove this function when Bytes can be passed in.
+    /// See `announce_vec`.
+    #[storage(read, write)]
+    fn revoke_vec(
+        validator: ValidatorKey,
+        storage_location_vec: Vec<u8>,
+        signature: B512,
+    ) {
+        revoke(validator, Bytes::from(storage_location_vec), signature);
+    }
+
+    /// Revokes a storage location previously announced by a validator.
+    /// Revoked storage locations are excluded from queries unless revoked
+    /// storage locations are explicitly included.
+    /// A revoked storage location cannot be announced again.
+    #[storage(read, write)]
+    fn revoke(
+        validator: ValidatorKey,
+        storage_location: Bytes,
+        signature: B512,
+    ) {
+        revoke(validator, storage_location, signature);
+    }
+
+    /// Returns all announced storage locations for each of the validators,
+    /// optionally including revoked storage locations.
     /// Note that tooling doesn't yet support nested heap return types, so
-    /// use of `get_announced_storage_location` is recommended in the short term.
+    /// use of `get_announced_storage_location` or `get_announced_storage_locations_paginated`
+    /// is recommended in the short term.
     /// Only intended for off-chain view calls due to potentially high gas costs.
     #[storage(read)]
-    fn get_announced_storage_locations(validators: Vec<EvmAddress>) -> Vec<Vec<Bytes>> {
+    fn get_announced_storage_locations(validators: Vec<ValidatorKey>, include_revoked: bool) -> Vec<Vec<Bytes>> {
         let validators_len = validators.len();
         let mut all_storage_locations = Vec::with_capacity(validators_len);
         let mut i = 0;
@@ -87,8 +113,9 @@ impl ValidatorAnnounce for Contract {
             let mut storage_locations = Vec::with_capacity(storage_location_count);
             let mut j = 0;
             while j < storage_location_count {
-                let storage_location = storage.storage_locations.get(validator, j).unwrap();
-                storage_locations.push(storage_location.into());
+                if include_revoked || !is_revoked(validator, j) {
+                    storage_locations.push(read_storage_location(validator, j).unwrap());
+                }
                 j += 1;
             }
             all_storage_locations.push(storage_locations);
@@ -98,53 +125,139 @@ impl ValidatorAnnounce for Contract {
         all_storage_locations
     }
 
+    /// Gets a storage location announced by a validator. If the index isn't specified,
+    /// defaults to the last announced storage location that hasn't been revoked,
+    /// or the last announced storage location if `include_revoked` is true.
+    /// Returns empty Bytes if there is no such storage location, or if the storage
+    /// location at the index has been revoked and `include_revoked` is false.
+    /// Reverts if the index is out of bounds.
     #[storage(read)]
     fn get_announced_storage_location(
-        validator: EvmAddress,
+        validator: ValidatorKey,
         storage_location_index: Option<u64>,
+        include_revoked: bool,
     ) -> Bytes {
-        let storage_location_count = storage.storage_locations.len(validator);
         // If no storage locations have been announced for this validator, return empty Bytes.
         // Note ideally this would be an `Option::None`, but fuels-rs doesn't support nested
         // heap types yet. This includes `Option<Bytes>`, `(Bytes, bool)`, etc.
-        if storage_location_count == 0 {
+        if storage.storage_locations.len(validator) == 0 {
             return Bytes::new();
         }
 
-        // If the index isn't specified, default to the last announced storage location.
-        let storage_location_index = storage_location_index.unwrap_or(storage_location_count - 1);
-        let storage_location = storage.storage_locations.get(validator, storage_location_index).expect("storage location index out of bounds");
+        let storage_location_index = match storage_location_index {
+            Option::Some(index) => index,
+            Option::None => {
+                match last_storage_location_index(validator, include_revoked) {
+                    Option::Some(index) => index,
+                    Option::None => return Bytes::new(),
+                }
+            },
+        };
 
-        storage_location.into()
+        let storage_location = read_storage_location(validator, storage_location_index).expect("storage location index out of bounds");
+        if !include_revoked && is_revoked(validator, storage_location_index) {
+            return Bytes::new();
+        }
+        storage_location
+    }
+
+    /// Returns true if the validator's storage location at the index has been revoked.
+    #[storage(read)]
+    fn is_storage_location_revoked(validator: ValidatorKey, storage_location_index: u64) -> bool {
+        is_revoked(validator, storage_location_index)
     }
 
     /// Gets the number of storage locations announced for a particular validator.
     #[storage(read)]
-    fn get_announced_storage_location_count(validator: EvmAddress) -> u64 {
+    fn get_announced_storage_location_count(validator: ValidatorKey) -> u64 {
         storage.storage_locations.len(validator)
     }
 
-    /// Gets all announced validators. Only intended for off-chain view calls due to
-    /// potentially high gas costs.
+    /// Gets up to `limit` of a validator's storage locations, starting at the index `offset`.
+    /// Revoked storage locations within the page are skipped unless `include_revoked` is true,
+    /// so a page may contain fewer than `limit` storage locations even if more remain.
+    /// Iterate offsets up to `get_announced_storage_location_count` to get all storage locations.
     #[storage(read)]
-    fn get_announced_validators() -> Vec<EvmAddress> {
-        let len = storage.validators_vec.len();
+    fn get_announced_storage_locations_paginated(
+        validator: ValidatorKey,
+        offset: u64,
+        limit: u64,
+        include_revoked: bool,
+    ) -> Vec<StorableString> {
+        let end = page_end(offset, limit, storage.storage_locations.len(validator));
 
-        let mut vec = Vec::with_capacity(len);
-        let mut i = 0;
-        while i < len {
-            vec.push(storage.validators_vec.get(i).unwrap());
+        let mut storage_locations = Vec::new();
+        let mut i = offset;
+        while i < end {
+            if include_revoked || !is_revoked(validator, i) {
+                storage_locations.push(StorableString::from(read_storage_location(validator, i).unwrap()));
+            }
             i += 1;
         }
-        vec
+        storage_locations
+    }
+
+    /// Gets all announced validators. Only intended for off-chain view calls due to
+    /// potentially high gas costs. Prefer `get_announced_validators_paginated`.
+    #[storage(read)]
+    fn get_announced_validators() -> Vec<ValidatorKey> {
+        get_announced_validators_paginated(0, storage.validators_vec.len())
+    }
+
+    /// Gets the number of announced validators.
+    #[storage(read)]
+    fn get_announced_validator_count() -> u64 {
+        storage.validators_vec.len()
+    }
+
+    /// Gets up to `limit` announced validators, starting at the index `offset`.
+    #[storage(read)]
+    fn get_announced_validators_paginated(offset: u64, limit: u64) -> Vec<ValidatorKey> {
+        get_announced_validators_paginated(offset, limit)
+    }
+}
+
+/// Gets up to `limit` announced validators, starting at the index `offset`.
+#[storage(read)]
+fn get_announced_validators_paginated(offset: u64, limit: u64) -> Vec<ValidatorKey> {
+    let end = page_end(offset, limit, storage.validators_vec.len());
+
+    let mut vec = Vec::with_capacity(end - offset);
+    let mut i = offset;
+    while i < end {
+        vec.push(storage.validators_vec.get(i).unwrap());
+        i += 1;
     }
+    vec
+}
+
+/// Gets the exclusive end index of a page of up to `limit` items starting
+/// at `offset`, bounded by `len`. Returns `offset` if the page is empty.
+fn page_end(offset: u64, limit: u64, len: u64) -> u64 {
+    if offset >= len {
+        offset
+    } else if limit > len - offset {
+        len
+    } else {
+        offset + limit
+    }
+}
+
+#[test()]
+fn test_page_end() {
+    assert(page_end(0, 10, 25) == 10);
+    assert(page_end(20, 10, 25) == 25);
+    assert(page_end(25, 10, 25) == 25);
+    assert(page_end(30, 10, 25) == 30);
+    // Doesn't overflow
+    assert(page_end(5, 18_446_744_073_709_551_615, 25) == 25);
 }
 
 /// If a validator is not already present in the validators map,
 /// it's added to the validators vec and the validators map.
 /// Idemptotent.
 #[storage(read, write)]
-fn upsert_validator(validator: EvmAddress) {
+fn upsert_validator(validator: ValidatorKey) {
     if storage.validators_map.get(validator).is_none() {
         storage.validators_vec.push(validator);
     }
@@ -154,29 +267,114 @@ fn upsert_validator(validator: EvmAddress) {
 /// Announces a validator's storage location.
 #[storage(read, write)]
 fn announce(
-    validator: EvmAddress,
+    validator: ValidatorKey,
     storage_location: Bytes,
     signature: B512,
 ) {
-    require(storage_location.len() <= MAX_STORABLE_STRING_CHARS, "storage location must be at most 128 characters");
+    require(storage_location.len() <= MAX_STORABLE_STRING_CHARS, "storage location must be at most 1024 characters");
 
     let replay_id = get_replay_id(validator, storage_location);
     require(storage.replay_protection.get(replay_id).is_none(), "validator and storage location already announced");
     storage.replay_protection.insert(replay_id, true);
 
-    let digest = get_announcement_digest(MAILBOX_ID, LOCAL_DOMAIN, storage_location);
+    let digest = get_announcement_digest(MAILBOX_ID, LOCAL_DOMAIN, validator, storage_location);
 
-    let signer = ec_recover_evm_address(signature, digest).expect("validator signature recovery failed");
-    require(validator == signer, "validator is not the signer");
+    require_signer(validator, digest, signature);
 
     upsert_validator(validator);
 
-    let storable = StorableString::from(storage_location);
-    storage.storage_locations.push(validator, storable);
+    let index = write_storage_location(validator, storage_location);
+    storage.storage_location_indices.insert(replay_id, index);
 
     // Log the announcement
     log(ValidatorAnnouncementEvent {
         validator,
-        storage_location: storable,
+        storage_location: StorableString::from(storage_location),
+    });
+}
+
+/// Revokes a validator's storage location.
+#[storage(read, write)]
+fn revoke(
+    validator: ValidatorKey,
+    storage_location: Bytes,
+    signature: B512,
+) {
+    let replay_id = get_replay_id(validator, storage_location);
+    let index = storage.storage_location_indices.get(replay_id).expect("storage location not announced");
+    require(!is_revoked(validator, index), "storage location already revoked");
+
+    let digest = get_revocation_digest(MAILBOX_ID, LOCAL_DOMAIN, validator, storage_location);
+    require_signer(validator, digest, signature);
+
+    storage.revoked_storage_locations.insert((validator, index), true);
+
+    // Log the revocation
+    log(ValidatorRevocationEvent {
+        validator,
+        storage_location: StorableString::from(storage_location),
     });
 }
+
+/// Reverts if the validator is not the signer of the digest.
+fn require_signer(validator: ValidatorKey, digest: b256, signature: B512) {
+    let signer = Signer::recover(signature, digest).expect("validator signature recovery failed");
+    require(signer.is(validator), "validator is not the signer");
+}
+
+/// Returns true if the validator's storage location at the index has been revoked.
+#[storage(read)]
+fn is_revoked(validator: ValidatorKey, index: u64) -> bool {
+    storage.revoked_storage_locations.get((validator, index)).unwrap_or(false)
+}
+
+/// Gets the index of the validator's last announced storage location,
+/// skipping revoked storage locations unless `include_revoked` is true.
+/// Returns None if there is no such storage location.
+#[storage(read)]
+fn last_storage_location_index(validator: ValidatorKey, include_revoked: bool) -> Option<u64> {
+    let mut index = storage.storage_locations.len(validator);
+    while index > 0 {
+        index -= 1;
+        if include_revoked || !is_revoked(validator, index) {
+            return Option::Some(index);
+        }
+    }
+    Option::None
+}
+
+/// Stores a new storage location for the validator, writing only the
+/// chunks needed to hold it. Returns the index of the storage location.
+#[storage(read, write)]
+fn write_storage_location(validator: ValidatorKey, storage_location: Bytes) -> u64 {
+    let index = storage.storage_locations.len(validator);
+    let len = storage_location.len();
+    storage.storage_locations.push(validator, len);
+
+    let count = chunk_count(len);
+    let mut chunk_index = 0;
+    while chunk_index < count {
+        storage.storage_location_chunks.insert((validator, index, chunk_index), get_chunk(storage_location, chunk_index));
+        chunk_index += 1;
+    }
+    index
+}
+
+/// Reads a validator's storage location at the index by reassembling its chunks,
+/// or None if the index is out of bounds.
+#[storage(read)]
+fn read_storage_location(validator: ValidatorKey, index: u64) -> Option<Bytes> {
+    let len = match storage.storage_locations.get(validator, index) {
+        Option::Some(len) => len,
+        Option::None => return Option::None,
+    };
+
+    let mut storage_location = Bytes::with_length(len);
+    let count = chunk_count(len);
+    let mut chunk_index = 0;
+    while chunk_index < count {
+        write_chunk(storage_location, chunk_index, storage.storage_location_chunks.get((validator, index, chunk_index)).unwrap());
+        chunk_index += 1;
+    }
+    Option::Some(storage_location)
+}
@@ -4,34 +4,78 @@ use std::bytes::Bytes;
 
 use std_lib_extended::bytes::*;
 
-pub const MAX_STORABLE_STRING_CHARS: u64 = 128;
+/// The number of bytes in a single chunk.
+pub const STORABLE_STRING_CHUNK_CHARS: u64 = 128;
+/// The max number of bytes in a string, i.e. 1 KiB.
+pub const MAX_STORABLE_STRING_CHARS: u64 = 1024;
 
 // As a workaround to account for storable types not yet being composable,
 // which precludes the use of StorageBytes, Bytes are instead represented
-// as a str[128] (which is a fixed length array of bytes) and a corresponding length.
+// as chunks of str[128] (which is a fixed length array of bytes) and a
+// corresponding length.
 //
-// This means that the largest string that can be stored is 128 bytes.
+// This means that the largest string that can be represented is 1024 bytes.
+// When stored, only the chunks needed to hold `len` bytes are written, so
+// short strings don't pay for the full 1024 bytes of storage.
 pub struct StorableString {
     len: u64,
-    string: str[128],
+    chunks: [str[128]; 8],
 }
 
 impl From<Bytes> for StorableString {
     fn from(bytes: Bytes) -> Self {
         let len = bytes.len();
-        let string = bytes_to_str_128(bytes);
-        StorableString { len, string }
+        require(len <= MAX_STORABLE_STRING_CHARS, "length of bytes must be <= 1024");
+
+        // Create copy that's 1024 bytes in length, for the same reason as
+        // in `bytes_to_str_128`.
+        let mut copy = Bytes::with_length(MAX_STORABLE_STRING_CHARS);
+        let _ = copy.write_bytes(0u64, bytes);
+
+        let read_ptr = copy.get_read_ptr(0, MAX_STORABLE_STRING_CHARS);
+        // convert the ptr to a [str[128]; 8]
+        let chunks = asm(ptr: read_ptr) { ptr: [str[128]; 8] };
+        StorableString { len, chunks }
     }
 
     fn into(self) -> Bytes {
         let mut bytes = Bytes::with_length(self.len);
-        let _ = bytes.write_packed_bytes(0u64, __addr_of(self.string), self.len);
+        let _ = bytes.write_packed_bytes(0u64, __addr_of(self.chunks), self.len);
         bytes
     }
 }
 
+/// Gets the number of chunks required to hold `len` bytes.
+pub fn chunk_count(len: u64) -> u64 {
+    (len + STORABLE_STRING_CHUNK_CHARS - 1) / STORABLE_STRING_CHUNK_CHARS
+}
+
+/// Gets the `index`th chunk of `bytes`, padded with zeroes to 128 bytes.
+pub fn get_chunk(bytes: Bytes, index: u64) -> str[128] {
+    let offset = index * STORABLE_STRING_CHUNK_CHARS;
+    let remaining = bytes.len() - offset;
+    let chunk_len = if remaining < STORABLE_STRING_CHUNK_CHARS {
+        remaining
+    } else {
+        STORABLE_STRING_CHUNK_CHARS
+    };
+    bytes_to_str_128(bytes.read_bytes(offset, chunk_len))
+}
+
+/// Writes `chunk` as the `index`th chunk of `bytes`, truncated to the bounds of `bytes`.
+pub fn write_chunk(ref mut bytes: Bytes, index: u64, chunk: str[128]) {
+    let offset = index * STORABLE_STRING_CHUNK_CHARS;
+    let remaining = bytes.len() - offset;
+    let chunk_len = if remaining < STORABLE_STRING_CHUNK_CHARS {
+        remaining
+    } else {
+        STORABLE_STRING_CHUNK_CHARS
+    };
+    let _ = bytes.write_packed_bytes(offset, __addr_of(chunk), chunk_len);
+}
+
 pub fn bytes_to_str_128(bytes: Bytes) -> str[128] {
-    require(bytes.len() <= MAX_STORABLE_STRING_CHARS, "length of bytes must be <= 128");
+    require(bytes.len() <= STORABLE_STRING_CHUNK_CHARS, "length of bytes must be <= 128");
 
     // Create copy that's 128 bytes in length.
     // It's possible for `bytes` to have a length < 128 bytes,
@@ -44,3 +88,36 @@ pub fn bytes_to_str_128(bytes: Bytes) -> str[128] {
     // convert the ptr to a str[128]
     asm(ptr: read_ptr) { ptr: str[128] }
 }
+
+#[test()]
+fn test_chunk_count() {
+    assert(chunk_count(0) == 0);
+    assert(chunk_count(1) == 1);
+    assert(chunk_count(128) == 1);
+    assert(chunk_count(129) == 2);
+    assert(chunk_count(1024) == 8);
+}
+
+#[test()]
+fn test_chunks_round_trip() {
+    let len = 300;
+    let mut bytes = Bytes::new();
+    let mut byte = 0u8;
+    while bytes.len() < len {
+        bytes.push(byte);
+        byte = if byte == 255u8 { 0u8 } else { byte + 1u8 };
+    }
+
+    let mut copy = Bytes::with_length(len);
+    let count = chunk_count(len);
+    let mut index = 0;
+    while index < count {
+        write_chunk(copy, index, get_chunk(bytes, index));
+        index += 1;
+    }
+    assert(copy.keccak256() == bytes.keccak256());
+
+    let storable = StorableString::from(bytes);
+    let round_tripped: Bytes = storable.into();
+    assert(round_tripped.keccak256() == bytes.keccak256());
+}
@@ -1,16 +1,19 @@
 use std::str::FromStr;
 
-use ethers::signers::Signer;
 use fuels::{
     prelude::*,
     programs::call_response::FuelCallResponse,
     tx::ContractId,
-    types::{Bits256, EvmAddress, SizedAsciiString},
+    types::{Bits256, SizedAsciiString},
 };
 
-use hyperlane_core::{Announcement, H256};
+use ethers::utils::keccak256;
+use hyperlane_core::{Signable, H256};
 use hyperlane_ethereum::Signers;
-use test_utils::{evm_address, get_revert_string, get_signer, sign_compact};
+use test_utils::{
+    decode_revert_reason, evm_address, fuel_address, get_all_pages, get_signer, sign_announcement,
+    sign_compact, sign_fuel_announcement, validator_announcement_events, RevertReason,
+};
 
 // Load abi from json
 abigen!(Contract(
@@ -21,14 +24,64 @@ abigen!(Contract(
 impl TryFrom<String> for StorableString {
     type Error = fuels::types::errors::Error;
     fn try_from(s: String) -> Result<Self> {
+        // Pad the string with null bytes on the right to the max length,
+        // then split it into 128 byte chunks
+        let padded = format!("{:\0<1024}", s);
+        let chunks = padded
+            .as_bytes()
+            .chunks(128)
+            .map(|chunk| SizedAsciiString::try_from(std::str::from_utf8(chunk).unwrap()))
+            .collect::<Result<Vec<_>>>()?;
         Ok(Self {
             len: s.len() as u64,
-            // Pad the string with null bytes on the right to the max length
-            string: SizedAsciiString::try_from(format!("{:\0<128}", s))?,
+            chunks: chunks.try_into().unwrap(),
         })
     }
 }
 
+impl From<StorableString> for String {
+    fn from(storable: StorableString) -> Self {
+        let mut s = storable
+            .chunks
+            .iter()
+            .map(|chunk| chunk.to_string())
+            .collect::<String>();
+        s.truncate(storable.len as usize);
+        s
+    }
+}
+
+/// A revocation of a previously announced storage location.
+/// Signed like an announcement, but with a domain hash suffix of "HYPERLANE_REVOCATION",
+/// or "HYPERLANE_REVOCATION_FUEL" for Fuel validators.
+#[derive(Clone, Debug)]
+struct Revocation {
+    mailbox_address: H256,
+    mailbox_domain: u32,
+    validator: ValidatorKey,
+    storage_location: String,
+}
+
+impl Signable for Revocation {
+    fn signing_hash(&self) -> H256 {
+        let suffix: &[u8] = match self.validator {
+            ValidatorKey::Evm(_) => b"HYPERLANE_REVOCATION",
+            ValidatorKey::Fuel(_) => b"HYPERLANE_REVOCATION_FUEL",
+        };
+        let domain_hash = keccak256(
+            [
+                &self.mailbox_domain.to_be_bytes()[..],
+                self.mailbox_address.as_bytes(),
+                suffix,
+            ]
+            .concat(),
+        );
+        H256(keccak256(
+            [&domain_hash[..], self.storage_location.as_bytes()].concat(),
+        ))
+    }
+}
+
 const TEST_MAILBOX_ID: &str = "0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe";
 const TEST_LOCAL_DOMAIN: u32 = 0x6675656cu32;
 
@@ -75,27 +128,88 @@ async fn get_contract_instance() -> (ValidatorAnnounce<WalletUnlocked>, Contract
     (instance, id.into())
 }
 
+/// The validator key of the signer's EVM address.
+fn evm_validator(signer: &Signers) -> ValidatorKey {
+    ValidatorKey::Evm(evm_address(signer))
+}
+
 async fn sign_and_announce(
     validator_announce: &ValidatorAnnounce<WalletUnlocked>,
     signer: &Signers,
     storage_location: String,
 ) -> Result<FuelCallResponse<()>> {
-    let mailbox_id = H256::from_str(TEST_MAILBOX_ID).unwrap();
-    let signer_address = signer.address();
+    sign_and_announce_as(
+        validator_announce,
+        signer,
+        evm_validator(signer),
+        storage_location,
+    )
+    .await
+}
+
+/// Signs an announcement and announces it as `validator`, which may be
+/// any type of key belonging to the signer.
+async fn sign_and_announce_as(
+    validator_announce: &ValidatorAnnounce<WalletUnlocked>,
+    signer: &Signers,
+    validator: ValidatorKey,
+    storage_location: String,
+) -> Result<FuelCallResponse<()>> {
+    let mailbox = H256::from_str(TEST_MAILBOX_ID).unwrap();
+    let compact_signed = match validator {
+        ValidatorKey::Evm(_) => {
+            sign_announcement(signer, mailbox, TEST_LOCAL_DOMAIN, &storage_location).await
+        }
+        ValidatorKey::Fuel(_) => {
+            sign_fuel_announcement(signer, mailbox, TEST_LOCAL_DOMAIN, &storage_location).await
+        }
+    };
+
+    validator_announce
+        .methods()
+        .announce_vec(
+            validator,
+            storage_location.as_bytes().into(),
+            compact_signed,
+        )
+        .call()
+        .await
+}
+
+async fn sign_and_revoke(
+    validator_announce: &ValidatorAnnounce<WalletUnlocked>,
+    signer: &Signers,
+    storage_location: String,
+) -> Result<FuelCallResponse<()>> {
+    sign_and_revoke_as(
+        validator_announce,
+        signer,
+        evm_validator(signer),
+        storage_location,
+    )
+    .await
+}
 
-    let announcement = Announcement {
-        validator: signer_address,
-        mailbox_address: mailbox_id,
+/// Signs a revocation and revokes the storage location as `validator`.
+async fn sign_and_revoke_as(
+    validator_announce: &ValidatorAnnounce<WalletUnlocked>,
+    signer: &Signers,
+    validator: ValidatorKey,
+    storage_location: String,
+) -> Result<FuelCallResponse<()>> {
+    let revocation = Revocation {
+        mailbox_address: H256::from_str(TEST_MAILBOX_ID).unwrap(),
         mailbox_domain: TEST_LOCAL_DOMAIN,
+        validator: validator.clone(),
         storage_location: storage_location.clone(),
     };
 
-    let compact_signed = sign_compact(signer, announcement).await;
+    let compact_signed = sign_compact(signer, revocation).await;
 
     validator_announce
         .methods()
-        .announce_vec(
-            evm_address(signer),
+        .revoke_vec(
+            validator,
             storage_location.as_bytes().into(),
             compact_signed,
         )
@@ -103,6 +217,22 @@ async fn sign_and_announce(
         .await
 }
 
+async fn get_announced_storage_location(
+    validator_announce: &ValidatorAnnounce<WalletUnlocked>,
+    validator: ValidatorKey,
+    storage_location_index: Option<u64>,
+    include_revoked: bool,
+) -> String {
+    let storage_location = validator_announce
+        .methods()
+        .get_announced_storage_location(validator, storage_location_index, include_revoked)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    String::from_utf8(storage_location.into()).unwrap()
+}
+
 // ================ announce ================
 
 #[tokio::test]
@@ -110,7 +240,7 @@ async fn test_announce() {
     let (validator_announce, _id) = get_contract_instance().await;
 
     let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
-    let signer_evm_address = evm_address(&signer);
+    let validator = evm_validator(&signer);
 
     let storage_location = "file://some/path/to/storage".to_string();
 
@@ -124,7 +254,7 @@ async fn test_announce() {
     assert_eq!(
         events,
         vec![ValidatorAnnouncementEvent {
-            validator: signer_evm_address,
+            validator,
             storage_location: storage_location.clone().try_into().unwrap(),
         }],
     );
@@ -133,8 +263,8 @@ async fn test_announce() {
     let call = sign_and_announce(&validator_announce, &signer, storage_location.clone()).await;
     assert!(call.is_err());
     assert_eq!(
-        get_revert_string(call.err().unwrap()),
-        "validator and storage location already announced"
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("validator and storage location already announced")
     );
 }
 
@@ -144,22 +274,18 @@ async fn test_announce_reverts_if_invalid_signature() {
 
     let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
 
-    let mailbox_id = H256::from_str(TEST_MAILBOX_ID).unwrap();
-
-    let validator_h160 = signer.address();
-
-    let non_signer_validator = evm_address(&get_signer(TEST_VALIDATOR_1_PRIVATE_KEY));
+    let non_signer_validator = evm_validator(&get_signer(TEST_VALIDATOR_1_PRIVATE_KEY));
 
     let storage_location = "file://some/path/to/storage";
-    let announcement = Announcement {
-        validator: validator_h160,
-        mailbox_address: mailbox_id,
-        mailbox_domain: TEST_LOCAL_DOMAIN,
-        storage_location: storage_location.into(),
-    };
 
     // Sign an announcement and announce it
-    let compact_signature = sign_compact(&signer, announcement).await;
+    let compact_signature = sign_announcement(
+        &signer,
+        H256::from_str(TEST_MAILBOX_ID).unwrap(),
+        TEST_LOCAL_DOMAIN,
+        storage_location,
+    )
+    .await;
     let call = validator_announce
         .methods()
         .announce_vec(
@@ -172,22 +298,412 @@ async fn test_announce_reverts_if_invalid_signature() {
         .await;
     assert!(call.is_err());
     assert_eq!(
-        get_revert_string(call.err().unwrap()),
-        "validator is not the signer"
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("validator is not the signer")
     );
 }
 
 #[tokio::test]
-async fn test_announce_reverts_if_storage_location_over_128_chars() {
+async fn test_announce_storage_location_chunk_boundaries() {
     let (validator_announce, _id) = get_contract_instance().await;
 
     let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
-    let storage_location = "a".repeat(129);
+    let validator = evm_validator(&signer);
+
+    // Lengths around the 128 byte chunk boundaries, up to the max of 1024 bytes
+    for len in [127, 128, 129, 256, 1023, 1024] {
+        // Vary the contents so that misordered chunks would be caught
+        let storage_location = (0..len)
+            .map(|i| char::from(b'a' + (i % 26) as u8))
+            .collect::<String>();
+
+        let call = sign_and_announce(&validator_announce, &signer, storage_location.clone())
+            .await
+            .unwrap();
+
+        let events = call
+            .get_logs_with_type::<ValidatorAnnouncementEvent>()
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![ValidatorAnnouncementEvent {
+                validator: validator.clone(),
+                storage_location: storage_location.clone().try_into().unwrap(),
+            }],
+        );
+
+        let announced_storage_location = validator_announce
+            .methods()
+            .get_announced_storage_location(validator.clone(), None, false)
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+        let announced_storage_location =
+            String::from_utf8(announced_storage_location.into()).unwrap();
+        assert_eq!(announced_storage_location, storage_location);
+    }
+}
+
+#[tokio::test]
+async fn test_announce_reverts_if_storage_location_over_1024_chars() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let storage_location = "a".repeat(1025);
     let call = sign_and_announce(&validator_announce, &signer, storage_location).await;
     assert!(call.is_err());
     assert_eq!(
-        get_revert_string(call.err().unwrap()),
-        "storage location must be at most 128 characters"
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("storage location must be at most 1024 characters")
+    );
+}
+
+#[tokio::test]
+async fn test_announce_fuel_validator() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let validator = ValidatorKey::Fuel(fuel_address(TEST_VALIDATOR_0_PRIVATE_KEY));
+
+    let storage_location = "file://some/path/to/storage".to_string();
+
+    let call = sign_and_announce_as(
+        &validator_announce,
+        &signer,
+        validator.clone(),
+        storage_location.clone(),
+    )
+    .await
+    .unwrap();
+
+    let events = call
+        .get_logs_with_type::<ValidatorAnnouncementEvent>()
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ValidatorAnnouncementEvent {
+            validator: validator.clone(),
+            storage_location: storage_location.clone().try_into().unwrap(),
+        }],
+    );
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, validator.clone(), None, false).await,
+        storage_location,
+    );
+
+    // The signer's EVM key is a distinct validator, so the same storage location
+    // can still be announced for it
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, evm_validator(&signer), None, false)
+            .await,
+        "",
+    );
+    sign_and_announce(&validator_announce, &signer, storage_location.clone())
+        .await
+        .unwrap();
+    let announced_validators = validator_announce
+        .methods()
+        .get_announced_validators()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        announced_validators,
+        vec![validator.clone(), evm_validator(&signer)]
+    );
+
+    // Fuel validators can revoke their storage locations too
+    sign_and_revoke_as(
+        &validator_announce,
+        &signer,
+        validator.clone(),
+        storage_location,
+    )
+    .await
+    .unwrap();
+    assert!(
+        validator_announce
+            .methods()
+            .is_storage_location_revoked(validator, 0)
+            .simulate()
+            .await
+            .unwrap()
+            .value
+    );
+}
+
+#[tokio::test]
+async fn test_announce_fuel_validator_reverts_if_invalid_signature() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    // Signed by validator 0, but announced as validator 1's Fuel address
+    let call = sign_and_announce_as(
+        &validator_announce,
+        &get_signer(TEST_VALIDATOR_0_PRIVATE_KEY),
+        ValidatorKey::Fuel(fuel_address(TEST_VALIDATOR_1_PRIVATE_KEY)),
+        "file://some/path/to/storage".to_string(),
+    )
+    .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("validator is not the signer")
+    );
+}
+
+#[tokio::test]
+async fn test_announce_fuel_validator_reverts_if_evm_signature() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let storage_location = "file://some/path/to/storage";
+
+    // An announcement signed for the signer's EVM key can't be replayed
+    // for its Fuel key
+    let evm_signature = sign_announcement(
+        &signer,
+        H256::from_str(TEST_MAILBOX_ID).unwrap(),
+        TEST_LOCAL_DOMAIN,
+        storage_location,
+    )
+    .await;
+    let call = validator_announce
+        .methods()
+        .announce_vec(
+            ValidatorKey::Fuel(fuel_address(TEST_VALIDATOR_0_PRIVATE_KEY)),
+            storage_location.as_bytes().into(),
+            evm_signature,
+        )
+        .call()
+        .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("validator is not the signer")
+    );
+}
+
+// ================ revoke ================
+
+#[tokio::test]
+async fn test_revoke() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let validator = evm_validator(&signer);
+
+    let first_storage_location = "file://some/path/to/storage".to_string();
+    let second_storage_location = "s3://some/s3/path".to_string();
+    for storage_location in [&first_storage_location, &second_storage_location] {
+        sign_and_announce(&validator_announce, &signer, storage_location.clone())
+            .await
+            .unwrap();
+    }
+
+    // Revoke the latest storage location
+    let call = sign_and_revoke(
+        &validator_announce,
+        &signer,
+        second_storage_location.clone(),
+    )
+    .await
+    .unwrap();
+
+    let events = call
+        .get_logs_with_type::<ValidatorRevocationEvent>()
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![ValidatorRevocationEvent {
+            validator: validator.clone(),
+            storage_location: second_storage_location.clone().try_into().unwrap(),
+        }],
+    );
+
+    for (index, revoked) in [(0, false), (1, true)] {
+        assert_eq!(
+            validator_announce
+                .methods()
+                .is_storage_location_revoked(validator.clone(), index)
+                .simulate()
+                .await
+                .unwrap()
+                .value,
+            revoked,
+        );
+    }
+
+    // The latest storage location that hasn't been revoked is now the first
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, validator.clone(), None, false).await,
+        first_storage_location,
+    );
+    // Unless revoked storage locations are included
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, validator.clone(), None, true).await,
+        second_storage_location,
+    );
+    // Revoked storage locations are empty when requested by index
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, validator.clone(), Some(1), false)
+            .await,
+        "",
+    );
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, validator.clone(), Some(1), true).await,
+        second_storage_location,
+    );
+
+    // Revoke the first storage location too, leaving none
+    sign_and_revoke(&validator_announce, &signer, first_storage_location.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, validator.clone(), None, false).await,
+        "",
+    );
+    assert_eq!(
+        get_announced_storage_location(&validator_announce, validator.clone(), None, true).await,
+        second_storage_location,
+    );
+
+    // The count includes revoked storage locations
+    assert_eq!(
+        validator_announce
+            .methods()
+            .get_announced_storage_location_count(validator.clone())
+            .simulate()
+            .await
+            .unwrap()
+            .value,
+        2,
+    );
+
+    // Revoked storage locations can't be announced again
+    let call = sign_and_announce(&validator_announce, &signer, first_storage_location).await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("validator and storage location already announced")
+    );
+}
+
+#[tokio::test]
+async fn test_revoke_reverts_if_not_announced() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+
+    let call = sign_and_revoke(
+        &validator_announce,
+        &signer,
+        "file://some/path/to/storage".to_string(),
+    )
+    .await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("storage location not announced")
+    );
+}
+
+#[tokio::test]
+async fn test_revoke_reverts_if_already_revoked() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let storage_location = "file://some/path/to/storage".to_string();
+
+    sign_and_announce(&validator_announce, &signer, storage_location.clone())
+        .await
+        .unwrap();
+    sign_and_revoke(&validator_announce, &signer, storage_location.clone())
+        .await
+        .unwrap();
+
+    let call = sign_and_revoke(&validator_announce, &signer, storage_location).await;
+    assert!(call.is_err());
+    assert_eq!(
+        decode_revert_reason(call.err().unwrap()).unwrap(),
+        RevertReason::require("storage location already revoked")
+    );
+}
+
+#[tokio::test]
+async fn test_revoke_reverts_if_invalid_signature() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let validator = evm_validator(&signer);
+    let storage_location = "file://some/path/to/storage".to_string();
+
+    sign_and_announce(&validator_announce, &signer, storage_location.clone())
+        .await
+        .unwrap();
+
+    // The announcement signature can't be used as a revocation signature
+    let announcement_signature = sign_announcement(
+        &signer,
+        H256::from_str(TEST_MAILBOX_ID).unwrap(),
+        TEST_LOCAL_DOMAIN,
+        &storage_location,
+    )
+    .await;
+
+    // Nor can a revocation signed by a different validator
+    let revocation = Revocation {
+        mailbox_address: H256::from_str(TEST_MAILBOX_ID).unwrap(),
+        mailbox_domain: TEST_LOCAL_DOMAIN,
+        validator: validator.clone(),
+        storage_location: storage_location.clone(),
+    };
+    let non_validator_signature =
+        sign_compact(&get_signer(TEST_VALIDATOR_1_PRIVATE_KEY), revocation).await;
+
+    for signature in [announcement_signature, non_validator_signature] {
+        let call = validator_announce
+            .methods()
+            .revoke_vec(
+                validator.clone(),
+                storage_location.as_bytes().into(),
+                signature,
+            )
+            .call()
+            .await;
+        assert!(call.is_err());
+        assert_eq!(
+            decode_revert_reason(call.err().unwrap()).unwrap(),
+            RevertReason::require("validator is not the signer")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_validator_announcement_events_exclude_revocations() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let storage_location = "file://some/path/to/storage".to_string();
+
+    let announce_call = sign_and_announce(&validator_announce, &signer, storage_location.clone())
+        .await
+        .unwrap();
+    let revoke_call = sign_and_revoke(&validator_announce, &signer, storage_location.clone())
+        .await
+        .unwrap();
+
+    // The revocation has the same type as the announcement, but isn't decoded as one
+    let receipts = [announce_call.receipts, revoke_call.receipts].concat();
+    let events =
+        validator_announcement_events(&validator_announce.log_decoder(), &receipts).unwrap();
+    assert_eq!(
+        events,
+        vec![test_utils::ValidatorAnnouncementEvent {
+            validator: test_utils::ValidatorKey::Evm(ethers::signers::Signer::address(&signer)),
+            storage_location,
+        }]
     );
 }
 
@@ -198,7 +714,7 @@ async fn test_get_announced_storage_location() {
     let (validator_announce, _id) = get_contract_instance().await;
 
     let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
-    let validator = evm_address(&signer);
+    let validator = evm_validator(&signer);
 
     let storage_location = "file://some/path/to/storage".to_string();
 
@@ -209,7 +725,7 @@ async fn test_get_announced_storage_location() {
     // Specify an index of None, defaulting to the latest storage location
     let announced_storage_location = validator_announce
         .methods()
-        .get_announced_storage_location(validator, None)
+        .get_announced_storage_location(validator.clone(), None, false)
         .simulate()
         .await
         .unwrap()
@@ -231,7 +747,7 @@ async fn test_get_announced_storage_location() {
     // Get the latest storage location, which should be the second announcement now
     let announced_storage_location = validator_announce
         .methods()
-        .get_announced_storage_location(validator, None)
+        .get_announced_storage_location(validator.clone(), None, false)
         .simulate()
         .await
         .unwrap()
@@ -242,7 +758,7 @@ async fn test_get_announced_storage_location() {
     // Ensure we can still get the first storage location
     let announced_storage_location = validator_announce
         .methods()
-        .get_announced_storage_location(validator, Some(0))
+        .get_announced_storage_location(validator.clone(), Some(0), false)
         .simulate()
         .await
         .unwrap()
@@ -256,11 +772,11 @@ async fn test_get_announced_storage_location_if_none_announced() {
     let (validator_announce, _id) = get_contract_instance().await;
 
     let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
-    let validator: EvmAddress = evm_address(&signer);
+    let validator = evm_validator(&signer);
 
     let storage_location = validator_announce
         .methods()
-        .get_announced_storage_location(validator, Some(0))
+        .get_announced_storage_location(validator.clone(), Some(0), false)
         .simulate()
         .await
         .unwrap()
@@ -274,7 +790,7 @@ async fn test_get_announced_storage_location_reverts_if_index_out_of_bounds() {
     let (validator_announce, _id) = get_contract_instance().await;
 
     let signer: Signers = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
-    let validator: EvmAddress = evm_address(&signer);
+    let validator = evm_validator(&signer);
 
     let storage_location = "file://some/path/to/storage".to_string();
     sign_and_announce(&validator_announce, &signer, storage_location)
@@ -284,13 +800,13 @@ async fn test_get_announced_storage_location_reverts_if_index_out_of_bounds() {
     // Specify an index of Some(1), which is out of bounds
     let storage_location = validator_announce
         .methods()
-        .get_announced_storage_location(validator, Some(1))
+        .get_announced_storage_location(validator.clone(), Some(1), false)
         .simulate()
         .await;
     assert!(storage_location.is_err());
     assert_eq!(
-        get_revert_string(storage_location.err().unwrap()),
-        "storage location index out of bounds"
+        decode_revert_reason(storage_location.err().unwrap()).unwrap(),
+        RevertReason::require("storage location index out of bounds")
     );
 }
 
@@ -301,7 +817,7 @@ async fn test_get_announced_storage_location_count() {
     let (validator_announce, _id) = get_contract_instance().await;
 
     let signer: Signers = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
-    let validator = evm_address(&signer);
+    let validator = evm_validator(&signer);
 
     let storage_location = "file://some/path/to/storage".to_string();
     sign_and_announce(&validator_announce, &signer, storage_location)
@@ -311,7 +827,7 @@ async fn test_get_announced_storage_location_count() {
     // Get the count of storage locations, expect 1
     let storage_location_count = validator_announce
         .methods()
-        .get_announced_storage_location_count(validator)
+        .get_announced_storage_location_count(validator.clone())
         .simulate()
         .await
         .unwrap()
@@ -326,7 +842,7 @@ async fn test_get_announced_storage_location_count() {
     // Get the count of storage locations, expect 2
     let storage_location_count = validator_announce
         .methods()
-        .get_announced_storage_location_count(validator)
+        .get_announced_storage_location_count(validator.clone())
         .simulate()
         .await
         .unwrap()
@@ -341,7 +857,7 @@ async fn test_get_announced_validators() {
     let (validator_announce, _id) = get_contract_instance().await;
 
     let signer_0: Signers = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
-    let validator_0 = evm_address(&signer_0);
+    let validator_0 = evm_validator(&signer_0);
 
     // No validators yet
     let announced_validators = validator_announce
@@ -365,11 +881,11 @@ async fn test_get_announced_validators() {
         .await
         .unwrap()
         .value;
-    assert_eq!(announced_validators, vec![validator_0]);
+    assert_eq!(announced_validators, vec![validator_0.clone()]);
 
     // New validator signer
     let signer_1: Signers = get_signer(TEST_VALIDATOR_1_PRIVATE_KEY);
-    let validator_1: EvmAddress = evm_address(&signer_1);
+    let validator_1 = evm_validator(&signer_1);
 
     sign_and_announce(&validator_announce, &signer_1, storage_location)
         .await
@@ -383,7 +899,10 @@ async fn test_get_announced_validators() {
         .await
         .unwrap()
         .value;
-    assert_eq!(announced_validators, vec![validator_0, validator_1]);
+    assert_eq!(
+        announced_validators,
+        vec![validator_0.clone(), validator_1.clone()]
+    );
 
     let second_storage_location = "file://a/different/path/to/storage".to_string();
     // Sign another announcement from validator_0 and announce it
@@ -399,5 +918,133 @@ async fn test_get_announced_validators() {
         .await
         .unwrap()
         .value;
-    assert_eq!(announced_validators, vec![validator_0, validator_1]);
+    assert_eq!(
+        announced_validators,
+        vec![validator_0.clone(), validator_1.clone()]
+    );
+}
+
+#[tokio::test]
+async fn test_get_announced_validators_paginated() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let validators = [TEST_VALIDATOR_0_PRIVATE_KEY, TEST_VALIDATOR_1_PRIVATE_KEY]
+        .into_iter()
+        .map(get_signer)
+        .collect::<Vec<_>>();
+    for signer in validators.iter() {
+        sign_and_announce(
+            &validator_announce,
+            signer,
+            "file://some/path/to/storage".to_string(),
+        )
+        .await
+        .unwrap();
+    }
+    let validators = validators.iter().map(evm_validator).collect::<Vec<_>>();
+
+    let count = validator_announce
+        .methods()
+        .get_announced_validator_count()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(count, 2);
+
+    for (offset, limit, expected) in [
+        (0, 1, vec![validators[0].clone()]),
+        (1, 1, vec![validators[1].clone()]),
+        (0, 10, validators.clone()),
+        (1, u64::MAX, vec![validators[1].clone()]),
+        (2, 1, vec![]),
+    ] {
+        let page = validator_announce
+            .methods()
+            .get_announced_validators_paginated(offset, limit)
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(page, expected);
+    }
+
+    let all_validators = get_all_pages(count, 1, |offset, limit| {
+        let call = validator_announce
+            .methods()
+            .get_announced_validators_paginated(offset, limit);
+        async move { Ok(call.simulate().await?.value) }
+    })
+    .await
+    .unwrap();
+    assert_eq!(all_validators, validators);
+}
+
+// ================ get_announced_storage_locations_paginated ================
+
+#[tokio::test]
+async fn test_get_announced_storage_locations_paginated() {
+    let (validator_announce, _id) = get_contract_instance().await;
+
+    let signer = get_signer(TEST_VALIDATOR_0_PRIVATE_KEY);
+    let validator = evm_validator(&signer);
+
+    let storage_locations = [
+        "file://some/path/to/storage".to_string(),
+        "s3://some/s3/path".to_string(),
+        // Spans multiple chunks
+        "gs://some/gcs/path/".repeat(20),
+    ];
+    for storage_location in storage_locations.iter() {
+        sign_and_announce(&validator_announce, &signer, storage_location.clone())
+            .await
+            .unwrap();
+    }
+    sign_and_revoke(&validator_announce, &signer, storage_locations[1].clone())
+        .await
+        .unwrap();
+
+    let count = validator_announce
+        .methods()
+        .get_announced_storage_location_count(validator.clone())
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    for (include_revoked, expected) in [
+        (
+            false,
+            vec![storage_locations[0].clone(), storage_locations[2].clone()],
+        ),
+        (true, storage_locations.to_vec()),
+    ] {
+        let all_storage_locations = get_all_pages(count, 2, |offset, limit| {
+            let call = validator_announce
+                .methods()
+                .get_announced_storage_locations_paginated(
+                    validator.clone(),
+                    offset,
+                    limit,
+                    include_revoked,
+                );
+            async move { Ok(call.simulate().await?.value) }
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+        assert_eq!(all_storage_locations, expected);
+    }
+
+    // Offsets past the end return an empty page
+    let page = validator_announce
+        .methods()
+        .get_announced_storage_locations_paginated(validator, count, 10, true)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(page, vec![]);
 }
@@ -11,6 +11,7 @@ import {
 
 import { HyperlaneMailboxAbi__factory } from '../types/factories/HyperlaneMailboxAbi__factory';
 import { HyperlaneMsgRecipientTestAbi__factory } from '../types/factories/HyperlaneMsgRecipientTestAbi__factory';
+import { ValidatorAnnounceAbi__factory } from '../types/factories/ValidatorAnnounceAbi__factory';
 
 // First default account from running fuel-client locally:
 //   Address: 0x6b63804cfbf9856e68e5b6e7aef238dc8311ec55bec04df774003a2c96e0418e
@@ -36,11 +37,13 @@ async function main() {
 
   const mailbox = await deployOrGetMailbox(wallet);
   const testRecipient = await deployOrGetTestRecipient(wallet);
+  const validatorAnnounce = await deployOrGetValidatorAnnounce(wallet);
 
   console.log('Contract IDs:');
   console.log({
     mailbox: mailbox.id.toHexString(),
     testRecipient: testRecipient.id.toHexString(),
+    validatorAnnounce: validatorAnnounce.id.toHexString(),
   });
 
   if (SEND_MESSAGE) {
@@ -87,6 +90,7 @@ async function deployOrGetContract(
   console.log('Deploying contract...');
   return factory.deployContract({
     salt: CONTRACT_SALT,
+    storageSlots,
   });
 }
 
@@ -120,6 +124,32 @@ async function deployOrGetTestRecipient(wallet: WalletUnlocked): Promise<Contrac
   );
 }
 
+// The indexer derives the ValidatorAnnounce's contract ID from the same bytecode,
+// salt and storage slots, so changes here must be mirrored in indexer/mailbox/build.rs.
+async function deployOrGetValidatorAnnounce(
+  wallet: WalletUnlocked,
+): Promise<Contract> {
+  const factory = new ContractFactory(
+    readFileSync(
+      '../contracts/validator-announce/out/debug/validator-announce.bin',
+    ),
+    ValidatorAnnounceAbi__factory.abi,
+    wallet,
+  );
+  const storageSlots: StorageSlot[] = JSON.parse(
+    readFileSync(
+      '../contracts/validator-announce/out/debug/validator-announce-storage_slots.json',
+      'utf8',
+    ),
+  );
+
+  return deployOrGetContract(
+    wallet,
+    factory,
+    storageSlots,
+  );
+}
+
 async function dispatchMessage(mailbox: Contract, testRecipient: Contract) {
   // Dispatch a message via the testRecipient, which takes in a Vec<u8> body
   // instead of the Mailbox's Bytes body, which isn't supported by fuels-ts yet.
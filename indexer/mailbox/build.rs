@@ -0,0 +1,100 @@
+//! Derives the ValidatorAnnounce's contract ID and the log ids of its `ValidatorAnnouncementEvent`
+//! and `ValidatorRevocationEvent` from the contract's build output, so they needn't be copied
+//! into `src/lib.rs` by hand.
+//! The contracts must be built first, e.g. with `yarn build` in the `deploy` package.
+
+use std::{env, fs, path::Path, str::FromStr};
+
+use fuel_tx::{Bytes32, Contract, Salt, StorageSlot};
+
+/// The ValidatorAnnounce's build output, relative to this package.
+const VALIDATOR_ANNOUNCE_OUT_DIR: &str = "../../contracts/validator-announce/out/debug";
+
+fn main() {
+    let out_dir = Path::new(VALIDATOR_ANNOUNCE_OUT_DIR);
+    let bin_path = out_dir.join("validator-announce.bin");
+    let storage_slots_path = out_dir.join("validator-announce-storage_slots.json");
+    let abi_path = out_dir.join("validator-announce-abi.json");
+    for path in [&bin_path, &storage_slots_path, &abi_path] {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+
+    let contract_id = contract_id(
+        &read(&bin_path),
+        &serde_json::from_slice(&read(&storage_slots_path)).expect("Invalid storage slots"),
+    );
+    let abi = serde_json::from_slice(&read(&abi_path)).expect("Invalid ABI");
+    let announcement_log_id = logged_type_log_id(&abi, "struct ValidatorAnnouncementEvent");
+    let revocation_log_id = logged_type_log_id(&abi, "struct ValidatorRevocationEvent");
+
+    let generated = format!(
+        r#"/// The contract ID of the ValidatorAnnounce, as deployed by the `deploy` package.
+const VALIDATOR_ANNOUNCE_CONTRACT_ID: &str = "{contract_id:#x}";
+
+/// The log id (i.e. the value of rB in the LogData) of a `ValidatorAnnouncementEvent` log.
+/// This is the `logId` of the event in the `loggedTypes` of the ValidatorAnnounce ABI.
+/// It must be used to distinguish announcements from revocations, which have the same layout.
+const VALIDATOR_ANNOUNCEMENT_EVENT_LOG_ID: u64 = {announcement_log_id};
+
+/// The log id of a `ValidatorRevocationEvent` log.
+const VALIDATOR_REVOCATION_EVENT_LOG_ID: u64 = {revocation_log_id};
+"#
+    );
+    let generated_path = Path::new(&env::var("OUT_DIR").unwrap()).join("validator_announce.rs");
+    fs::write(generated_path, generated).unwrap();
+}
+
+fn read(path: &Path) -> Vec<u8> {
+    fs::read(path).unwrap_or_else(|err| {
+        panic!(
+            "Failed to read {}, have the contracts been built? {err}",
+            path.display()
+        )
+    })
+}
+
+/// The ID of the contract when deployed with the `deploy` package's salt of zero
+/// and its default configurables.
+/// See https://fuellabs.github.io/fuel-specs/master/protocol/id/contract.html
+fn contract_id(bytecode: &[u8], storage_slots: &serde_json::Value) -> fuel_tx::ContractId {
+    let storage_slots = storage_slots
+        .as_array()
+        .expect("Storage slots must be an array")
+        .iter()
+        .map(|slot| {
+            let word = |field: &str| {
+                Bytes32::from_str(slot[field].as_str().expect("Invalid storage slot"))
+                    .expect("Invalid storage slot")
+            };
+            StorageSlot::new(word("key"), word("value"))
+        })
+        .collect::<Vec<_>>();
+
+    let contract = Contract::from(bytecode.to_vec());
+    let root = contract.root();
+    let state_root = Contract::initial_state_root(storage_slots.iter());
+    contract.id(&Salt::zeroed(), &root, &state_root)
+}
+
+/// The `logId` of the type named `type_name` in the `loggedTypes` of the ABI.
+fn logged_type_log_id(abi: &serde_json::Value, type_name: &str) -> u64 {
+    let type_id = abi["types"]
+        .as_array()
+        .and_then(|types| {
+            types
+                .iter()
+                .find(|ty| ty["type"].as_str() == Some(type_name))
+        })
+        .and_then(|ty| ty["typeId"].as_u64())
+        .unwrap_or_else(|| panic!("{type_name} not in ABI"));
+
+    abi["loggedTypes"]
+        .as_array()
+        .and_then(|logged_types| {
+            logged_types
+                .iter()
+                .find(|logged| logged["loggedType"]["type"].as_u64() == Some(type_id))
+        })
+        .and_then(|logged| logged["logId"].as_u64())
+        .unwrap_or_else(|| panic!("{type_name} not logged in ABI"))
+}
@@ -0,0 +1,202 @@
+use primitive_types::H256;
+
+use crate::encode::{Decode, DecodeError};
+
+/// The max length of a StorableString in bytes.
+const MAX_STORABLE_STRING_LEN: u64 = 1024;
+
+/// A string as represented by the `StorableString` type in the ValidatorAnnounce contract.
+///
+/// Encoded as:
+/// |  8 bytes  |           1024 bytes           |
+/// [len as u64][bytes, right padded with zeroes]
+#[derive(Debug, Default, Clone)]
+pub struct StorableString(pub Vec<u8>);
+
+impl Decode for StorableString {
+    fn read_from<R>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        R: std::io::Read,
+    {
+        let len = u64::read_from(reader)?;
+        if len > MAX_STORABLE_STRING_LEN {
+            return Err(DecodeError::InvalidStorableStringLength(len));
+        }
+
+        let mut bytes = vec![0u8; MAX_STORABLE_STRING_LEN as usize];
+        reader.read_exact(&mut bytes)?;
+        bytes.truncate(len as usize);
+
+        Ok(Self(bytes))
+    }
+}
+
+/// A validator's identity as represented by the `ValidatorKey` enum in the
+/// hyperlane-interfaces library, tagged by the type of key it signs with.
+///
+/// Encoded as:
+/// |  8 bytes  |  32 bytes  |
+/// [tag as u64][  address  ]
+///
+/// An `Evm` key is an EvmAddress, which is a b256 with the
+/// 12 most significant bytes set to zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorKey {
+    Evm(H256),
+    Fuel(H256),
+}
+
+impl ValidatorKey {
+    const EVM_TAG: u64 = 0;
+    const FUEL_TAG: u64 = 1;
+
+    /// The enum tag, identifying the type of key.
+    pub fn tag(&self) -> u64 {
+        match self {
+            ValidatorKey::Evm(_) => Self::EVM_TAG,
+            ValidatorKey::Fuel(_) => Self::FUEL_TAG,
+        }
+    }
+
+    /// The address, left padded with zeroes to 32 bytes.
+    pub fn address(&self) -> H256 {
+        match self {
+            ValidatorKey::Evm(address) | ValidatorKey::Fuel(address) => *address,
+        }
+    }
+
+    /// The address as used in the replay ID, i.e. 20 bytes for EVM keys
+    /// and 32 bytes for Fuel keys.
+    pub fn packed_address(&self) -> &[u8] {
+        match self {
+            ValidatorKey::Evm(address) => &address.as_bytes()[12..],
+            ValidatorKey::Fuel(address) => address.as_bytes(),
+        }
+    }
+}
+
+impl Default for ValidatorKey {
+    fn default() -> Self {
+        ValidatorKey::Evm(H256::zero())
+    }
+}
+
+impl Decode for ValidatorKey {
+    fn read_from<R>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        R: std::io::Read,
+    {
+        let tag = u64::read_from(reader)?;
+        let address = H256::read_from(reader)?;
+        match tag {
+            Self::EVM_TAG => Ok(ValidatorKey::Evm(address)),
+            Self::FUEL_TAG => Ok(ValidatorKey::Fuel(address)),
+            _ => Err(DecodeError::InvalidValidatorKeyTag(tag)),
+        }
+    }
+}
+
+/// The `ValidatorAnnouncementEvent` logged by the ValidatorAnnounce contract.
+///
+/// Encoded as:
+/// |    40 bytes    |    1032 bytes    |
+/// [validator      ][storage location ]
+#[derive(Debug, Default, Clone)]
+pub struct ValidatorAnnouncementEvent {
+    pub validator: ValidatorKey,
+    pub storage_location: StorableString,
+}
+
+impl Decode for ValidatorAnnouncementEvent {
+    fn read_from<R>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        R: std::io::Read,
+    {
+        Ok(Self {
+            validator: ValidatorKey::read_from(reader)?,
+            storage_location: StorableString::read_from(reader)?,
+        })
+    }
+}
+
+/// The `ValidatorRevocationEvent` logged by the ValidatorAnnounce contract,
+/// which is encoded the same way as a `ValidatorAnnouncementEvent`.
+pub type ValidatorRevocationEvent = ValidatorAnnouncementEvent;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes an event as logged by the ValidatorAnnounce.
+    fn encode_event(tag: u64, address: H256, storage_location: &str) -> Vec<u8> {
+        let mut padded = storage_location.as_bytes().to_vec();
+        padded.resize(MAX_STORABLE_STRING_LEN as usize, 0);
+        [
+            &tag.to_be_bytes()[..],
+            address.as_bytes(),
+            &(storage_location.len() as u64).to_be_bytes(),
+            &padded,
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_read_evm_validator_announcement_event() {
+        let mut address = H256::zero();
+        address.as_bytes_mut()[12..].copy_from_slice(&[0xcd; 20]);
+        // 27 bytes, not a multiple of the contract's 128 byte chunks
+        let storage_location = "file://some/path/to/storage";
+
+        let data = encode_event(0, address, storage_location);
+        assert_eq!(data.len(), 1072);
+
+        let event = ValidatorAnnouncementEvent::read_from(&mut data.as_slice()).unwrap();
+        assert_eq!(event.validator, ValidatorKey::Evm(address));
+        assert_eq!(event.validator.packed_address(), &[0xcd; 20]);
+        assert_eq!(event.storage_location.0, storage_location.as_bytes());
+    }
+
+    #[test]
+    fn test_read_fuel_validator_announcement_event() {
+        let address = H256::repeat_byte(0xab);
+        // Spans multiple chunks, ending part way through one
+        let storage_location = format!("s3://{}", "a".repeat(200));
+
+        let data = encode_event(1, address, &storage_location);
+        let event = ValidatorAnnouncementEvent::read_from(&mut data.as_slice()).unwrap();
+        assert_eq!(event.validator, ValidatorKey::Fuel(address));
+        assert_eq!(event.validator.packed_address(), address.as_bytes());
+        assert_eq!(event.storage_location.0, storage_location.as_bytes());
+    }
+
+    #[test]
+    fn test_read_validator_key_rejects_unknown_tag() {
+        let data = encode_event(2, H256::zero(), "");
+        assert!(matches!(
+            ValidatorKey::read_from(&mut data.as_slice()),
+            Err(DecodeError::InvalidValidatorKeyTag(2))
+        ));
+    }
+
+    #[test]
+    fn test_read_storable_string() {
+        // Empty and max length strings
+        for storage_location in [String::new(), "a".repeat(MAX_STORABLE_STRING_LEN as usize)] {
+            let data = encode_event(0, H256::zero(), &storage_location);
+            let string = StorableString::read_from(&mut &data[40..]).unwrap();
+            assert_eq!(string.0, storage_location.as_bytes());
+        }
+
+        // Lengths greater than the max are rejected
+        let mut data = encode_event(0, H256::zero(), "");
+        data[40..48].copy_from_slice(&(MAX_STORABLE_STRING_LEN + 1).to_be_bytes());
+        assert!(matches!(
+            StorableString::read_from(&mut &data[40..]),
+            Err(DecodeError::InvalidStorableStringLength(1025))
+        ));
+
+        // As is data shorter than the padded string
+        let data = encode_event(0, H256::zero(), "file://some/path/to/storage");
+        assert!(StorableString::read_from(&mut &data[40..100]).is_err());
+    }
+}
@@ -10,6 +10,12 @@ pub enum DecodeError {
     /// IO error from Read/Write usage
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    /// A StorableString with a length greater than its max length
+    #[error("Invalid StorableString length: {0}")]
+    InvalidStorableStringLength(u64),
+    /// A ValidatorKey with an unknown enum tag
+    #[error("Invalid ValidatorKey tag: {0}")]
+    InvalidValidatorKeyTag(u64),
 }
 
 /// Simple trait for types with a canonical encoding
@@ -1,3 +1,4 @@
+mod announcement;
 mod encode;
 mod message;
 
@@ -6,7 +7,13 @@ extern crate alloc;
 use fuel_indexer_macros::indexer;
 use fuel_indexer_plugin::prelude::*;
 
-use crate::{encode::Decode, message::HyperlaneMessage};
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    announcement::{ValidatorAnnouncementEvent, ValidatorKey, ValidatorRevocationEvent},
+    encode::Decode,
+    message::HyperlaneMessage,
+};
 
 /// The log id (i.e. the value of rB in the LogData) of a dispatched message log.
 /// "hyp" in bytes
@@ -17,6 +24,11 @@ const DISPATCHED_MESSAGE_LOG_ID: u64 = 0x687970u64;
 const MAILBOX_CONTRACT_ID: &str =
     "0x968a13b3bd8310939636afa85b64bd2a533dbd770bc21f0a00e0def18c32ee2b";
 
+// Defines `VALIDATOR_ANNOUNCE_CONTRACT_ID`, `VALIDATOR_ANNOUNCEMENT_EVENT_LOG_ID` and
+// `VALIDATOR_REVOCATION_EVENT_LOG_ID`, which are derived from the ValidatorAnnounce's
+// build output by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/validator_announce.rs"));
+
 struct LogMetadata {
     contract_id: Address,
     block_number: u64,
@@ -54,12 +66,100 @@ impl DispatchedMessage {
     }
 }
 
+/// Gets an ID from the first 8 bytes of a hash.
+fn id_from_hash(hash: &[u8]) -> u64 {
+    u64::from_be_bytes(hash[..8].try_into().unwrap())
+}
+
+impl AnnouncedValidator {
+    fn id_for(validator: ValidatorKey) -> u64 {
+        let hash = Keccak256::new()
+            .chain(validator.tag().to_be_bytes())
+            .chain(validator.address().as_bytes())
+            .finalize();
+        id_from_hash(hash.as_slice())
+    }
+}
+
+impl ValidatorAnnouncement {
+    fn id_for(validator: ValidatorKey, storage_location: &[u8]) -> u64 {
+        // The replay ID is the hash of the validator's address and the storage location.
+        let replay_id = Keccak256::new()
+            .chain(validator.packed_address())
+            .chain(storage_location)
+            .finalize();
+        id_from_hash(replay_id.as_slice())
+    }
+
+    fn new(
+        event: ValidatorAnnouncementEvent,
+        storage_location_index: u64,
+        log_metadata: LogMetadata,
+    ) -> Self {
+        Self {
+            id: Self::id_for(event.validator, &event.storage_location.0),
+
+            validator: Bytes32::from(event.validator.address().to_fixed_bytes()),
+            validator_key_type: event.validator.tag() as u32,
+            storage_location: event.storage_location.0,
+            storage_location_index,
+            revoked: false,
+
+            // Log metadata
+            contract_id: log_metadata.contract_id,
+            block_number: log_metadata.block_number,
+            block_hash: log_metadata.block_hash,
+            transaction_hash: log_metadata.transaction_hash,
+            transaction_index: log_metadata.transaction_index,
+            receipt_index: log_metadata.receipt_index,
+        }
+    }
+}
+
+/// Saves a validator announcement, incrementing the validator's storage location count.
+fn index_validator_announcement(event: ValidatorAnnouncementEvent, log_metadata: LogMetadata) {
+    let validator_id = AnnouncedValidator::id_for(event.validator);
+    let mut announced_validator =
+        AnnouncedValidator::load(validator_id).unwrap_or_else(|| AnnouncedValidator {
+            id: validator_id,
+            validator: Bytes32::from(event.validator.address().to_fixed_bytes()),
+            validator_key_type: event.validator.tag() as u32,
+            storage_location_count: 0,
+        });
+
+    let announcement = ValidatorAnnouncement::new(
+        event,
+        announced_validator.storage_location_count,
+        log_metadata,
+    );
+    announcement.save();
+
+    announced_validator.storage_location_count += 1;
+    announced_validator.save();
+}
+
+/// Marks a validator's announced storage location as revoked.
+fn index_validator_revocation(event: ValidatorRevocationEvent) {
+    let id = ValidatorAnnouncement::id_for(event.validator, &event.storage_location.0);
+    match ValidatorAnnouncement::load(id) {
+        Some(mut announcement) => {
+            announcement.revoked = true;
+            announcement.save();
+        }
+        None => Logger::error(&format!(
+            "Revoked ValidatorAnnouncement {id} has not been indexed"
+        )),
+    }
+}
+
 #[indexer(manifest = "mailbox.manifest.yaml")]
 mod mailbox_indexer {
 
     fn index_block(block_data: BlockData) {
         let mailbox_contract =
             ContractId::from_str(MAILBOX_CONTRACT_ID).expect("Invalid Mailbox contract ID");
+        let validator_announce_contract = ContractId::from_str(VALIDATOR_ANNOUNCE_CONTRACT_ID)
+            .expect("Invalid ValidatorAnnounce contract ID");
 
         let mut transaction_index = 0;
         for tx in block_data.transactions.iter() {
@@ -71,30 +171,43 @@ mod mailbox_indexer {
             let mut receipt_index = 0;
             for receipt in &tx.receipts {
                 if let Receipt::LogData { id, rb, data, .. } = receipt {
-                    // Ignore if the receipt isn't from the Mailbox
-                    if *id != mailbox_contract {
-                        continue;
-                    }
+                    let log_metadata = LogMetadata {
+                        contract_id: Address::new((*id).into()),
+                        block_number: block_data.height,
+                        block_hash: block_data.id,
+                        transaction_hash: tx.id,
+                        transaction_index,
+                        receipt_index,
+                    };
 
                     // rb is the where the log ID is found.
                     // A special marker value is used to identify dispatched messages.
-                    if *rb != DISPATCHED_MESSAGE_LOG_ID {
-                        continue;
+                    if *id == mailbox_contract && *rb == DISPATCHED_MESSAGE_LOG_ID {
+                        let dispatched_message = DispatchedMessage::new(
+                            HyperlaneMessage::read_from(&mut data.as_slice())
+                                .expect("Malformed HyperlaneMessage log data"),
+                            log_metadata,
+                        );
+                        dispatched_message.save();
+                    } else if *id == validator_announce_contract
+                        && *rb == VALIDATOR_ANNOUNCEMENT_EVENT_LOG_ID
+                    {
+                        match ValidatorAnnouncementEvent::read_from(&mut data.as_slice()) {
+                            Ok(event) => index_validator_announcement(event, log_metadata),
+                            Err(err) => Logger::error(&format!(
+                                "Skipping malformed ValidatorAnnouncementEvent log data: {err}"
+                            )),
+                        }
+                    } else if *id == validator_announce_contract
+                        && *rb == VALIDATOR_REVOCATION_EVENT_LOG_ID
+                    {
+                        match ValidatorRevocationEvent::read_from(&mut data.as_slice()) {
+                            Ok(event) => index_validator_revocation(event),
+                            Err(err) => Logger::error(&format!(
+                                "Skipping malformed ValidatorRevocationEvent log data: {err}"
+                            )),
+                        }
                     }
-
-                    let dispatched_message = DispatchedMessage::new(
-                        HyperlaneMessage::read_from(&mut data.as_slice())
-                            .expect("Malformed HyperlaneMessage log data"),
-                        LogMetadata {
-                            contract_id: Address::new(mailbox_contract.into()),
-                            block_number: block_data.height,
-                            block_hash: block_data.id,
-                            transaction_hash: tx.id,
-                            transaction_index,
-                            receipt_index,
-                        },
-                    );
-                    dispatched_message.save();
                 }
 
                 receipt_index += 1;
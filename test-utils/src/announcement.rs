@@ -0,0 +1,174 @@
+use ethers::{
+    signers::Signer,
+    utils::{hash_message, keccak256},
+};
+use fuels::types::B512;
+use hyperlane_core::{Announcement, Signable, H256};
+use hyperlane_ethereum::Signers;
+
+use crate::sign_compact;
+
+fn domain_hash(mailbox: H256, domain: u32, suffix: &[u8]) -> H256 {
+    H256(keccak256(
+        [&domain.to_be_bytes()[..], mailbox.as_bytes(), suffix].concat(),
+    ))
+}
+
+/// The announcement domain hash.
+/// Mirrors `domain_hash` in validator-announce/src/digest.sw, i.e.
+/// keccak256(local_domain ++ mailbox ++ "HYPERLANE_ANNOUNCEMENT").
+pub fn announcement_domain_hash(mailbox: H256, domain: u32) -> H256 {
+    domain_hash(mailbox, domain, b"HYPERLANE_ANNOUNCEMENT")
+}
+
+/// The announcement domain hash for Fuel validators, i.e.
+/// keccak256(local_domain ++ mailbox ++ "HYPERLANE_ANNOUNCEMENT_FUEL").
+fn fuel_announcement_domain_hash(mailbox: H256, domain: u32) -> H256 {
+    domain_hash(mailbox, domain, b"HYPERLANE_ANNOUNCEMENT_FUEL")
+}
+
+/// The digest a validator signs to announce a storage location.
+/// Mirrors `get_announcement_digest` in validator-announce/src/digest.sw.
+pub fn announcement_digest(mailbox: H256, domain: u32, storage_location: &str) -> H256 {
+    let domain_hash = announcement_domain_hash(mailbox, domain);
+    let signed_message_hash =
+        keccak256([domain_hash.as_bytes(), storage_location.as_bytes()].concat());
+    hash_message(signed_message_hash)
+}
+
+/// The digest a Fuel validator signs to announce a storage location.
+/// Mirrors `get_announcement_digest` in validator-announce/src/digest.sw.
+pub fn fuel_announcement_digest(mailbox: H256, domain: u32, storage_location: &str) -> H256 {
+    FuelAnnouncement {
+        mailbox_address: mailbox,
+        mailbox_domain: domain,
+        storage_location: storage_location.to_string(),
+    }
+    .eth_signed_message_hash()
+}
+
+/// Signs an announcement of the storage location for the ValidatorAnnounce
+/// of the mailbox and domain, returning the compact signature.
+pub async fn sign_announcement(
+    signer: &Signers,
+    mailbox: H256,
+    domain: u32,
+    storage_location: &str,
+) -> B512 {
+    let announcement = Announcement {
+        validator: signer.address(),
+        mailbox_address: mailbox,
+        mailbox_domain: domain,
+        storage_location: storage_location.to_string(),
+    };
+    sign_compact(signer, announcement).await
+}
+
+/// Signs an announcement of the storage location as the signer's Fuel
+/// validator key, returning the compact signature.
+pub async fn sign_fuel_announcement(
+    signer: &Signers,
+    mailbox: H256,
+    domain: u32,
+    storage_location: &str,
+) -> B512 {
+    let announcement = FuelAnnouncement {
+        mailbox_address: mailbox,
+        mailbox_domain: domain,
+        storage_location: storage_location.to_string(),
+    };
+    sign_compact(signer, announcement).await
+}
+
+/// An announcement by a Fuel validator.
+/// Signed like an announcement, but with the Fuel announcement domain hash.
+#[derive(Clone, Debug)]
+struct FuelAnnouncement {
+    mailbox_address: H256,
+    mailbox_domain: u32,
+    storage_location: String,
+}
+
+impl Signable for FuelAnnouncement {
+    fn signing_hash(&self) -> H256 {
+        let domain_hash = fuel_announcement_domain_hash(self.mailbox_address, self.mailbox_domain);
+        H256(keccak256(
+            [domain_hash.as_bytes(), self.storage_location.as_bytes()].concat(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{fuel_address, get_signer, recover_fuel_address};
+
+    const TEST_VALIDATOR_PRIVATE_KEY: &str =
+        "2ef987da35e5b389bb47cc4ec024ce0c37e5defd00de35fe61db6f50d1a858a1";
+
+    // The same vector is used by `test_get_announcement_digest` in validator-announce/src/digest.sw.
+    const TEST_MAILBOX: &str = "0xcafecafecafecafecafecafecafecafecafecafecafecafecafecafecafecafe";
+    const TEST_DOMAIN: u32 = 0x6675656cu32;
+    const TEST_STORAGE_LOCATION: &str = "file://some/path/to/storage";
+    const TEST_DIGEST: &str = "0x9923e6d2c24809fd881d27d2e81745cd3ea397969cc6bef1fdc613e0a5bb42f0";
+
+    #[test]
+    fn test_announcement_digest() {
+        let mailbox = H256::from_str(TEST_MAILBOX).unwrap();
+        let digest = announcement_digest(mailbox, TEST_DOMAIN, TEST_STORAGE_LOCATION);
+        assert_eq!(digest, H256::from_str(TEST_DIGEST).unwrap());
+
+        // Consistent with the announcements signed by the agents
+        let announcement = Announcement {
+            validator: Default::default(),
+            mailbox_address: mailbox,
+            mailbox_domain: TEST_DOMAIN,
+            storage_location: TEST_STORAGE_LOCATION.to_string(),
+        };
+        assert_eq!(digest, announcement.eth_signed_message_hash());
+    }
+
+    #[tokio::test]
+    async fn test_sign_announcement() {
+        let mailbox = H256::from_str(TEST_MAILBOX).unwrap();
+        let signature = sign_announcement(
+            &get_signer(TEST_VALIDATOR_PRIVATE_KEY),
+            mailbox,
+            TEST_DOMAIN,
+            TEST_STORAGE_LOCATION,
+        )
+        .await;
+
+        let digest = announcement_digest(mailbox, TEST_DOMAIN, TEST_STORAGE_LOCATION);
+        assert_eq!(
+            recover_fuel_address(&signature, digest),
+            fuel_address(TEST_VALIDATOR_PRIVATE_KEY)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_fuel_announcement() {
+        let mailbox = H256::from_str(TEST_MAILBOX).unwrap();
+        let signature = sign_fuel_announcement(
+            &get_signer(TEST_VALIDATOR_PRIVATE_KEY),
+            mailbox,
+            TEST_DOMAIN,
+            TEST_STORAGE_LOCATION,
+        )
+        .await;
+
+        let digest = fuel_announcement_digest(mailbox, TEST_DOMAIN, TEST_STORAGE_LOCATION);
+        assert_eq!(
+            recover_fuel_address(&signature, digest),
+            fuel_address(TEST_VALIDATOR_PRIVATE_KEY)
+        );
+
+        // Fuel announcements can't be replayed as EVM announcements
+        assert_ne!(
+            digest,
+            announcement_digest(mailbox, TEST_DOMAIN, TEST_STORAGE_LOCATION)
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use fuels::types::B512;
+use hyperlane_core::{
+    accumulator::{merkle::MerkleTree, TREE_DEPTH},
+    Checkpoint, HyperlaneMessage, Signable, H256,
+};
+use hyperlane_ethereum::Signers;
+
+use crate::{encode_multisig_metadata, sign_compact};
+
+/// Signs checkpoints of an origin Mailbox on behalf of a set of validators,
+/// producing metadata in the format expected by the MultisigIsm.
+///
+/// Signatures are ordered by validator, so validators should be provided in the
+/// order they are enrolled in the MultisigIsm. Only the first `threshold` signatures
+/// are read by the MultisigIsm, so provide only the validators that should sign.
+pub struct CheckpointSigner {
+    validators: Vec<Signers>,
+    mailbox: H256,
+    origin: u32,
+}
+
+impl CheckpointSigner {
+    pub fn new(validators: Vec<Signers>, mailbox: H256, origin: u32) -> Self {
+        Self {
+            validators,
+            mailbox,
+            origin,
+        }
+    }
+
+    /// The checkpoint of the origin Mailbox's tree at the root and index.
+    pub fn checkpoint(&self, root: H256, index: u32) -> Checkpoint {
+        Checkpoint {
+            mailbox_address: self.mailbox,
+            mailbox_domain: self.origin,
+            root,
+            index,
+        }
+    }
+
+    /// The digest that validators sign, i.e. the EIP-191 prefixed checkpoint hash.
+    /// Equivalent to `MultisigMetadata::checkpoint_digest` in multisig-ism-metadata.
+    pub fn checkpoint_digest(&self, root: H256, index: u32) -> H256 {
+        self.checkpoint(root, index).eth_signed_message_hash()
+    }
+
+    /// Signs the checkpoint with each validator, returning compact signatures.
+    pub async fn sign_checkpoint(&self, root: H256, index: u32) -> Vec<B512> {
+        let checkpoint = self.checkpoint(root, index);
+        let mut signatures = Vec::with_capacity(self.validators.len());
+        for validator in self.validators.iter() {
+            signatures.push(sign_compact(validator, checkpoint).await);
+        }
+        signatures
+    }
+
+    /// Signs the checkpoint and encodes it with the merkle proof of the leaf
+    /// at the index into MultisigIsm metadata.
+    pub async fn metadata(&self, root: H256, index: u32, proof: &[H256]) -> Vec<u8> {
+        let signatures = self.sign_checkpoint(root, index).await;
+        encode_multisig_metadata(&root, index, &self.mailbox, &proof.to_vec(), &signatures)
+    }
+
+    /// Signs a checkpoint of `tree` proving the inclusion of `message`, and returns
+    /// metadata that can be passed alongside the message to `Mailbox.process`.
+    /// `tree` must mirror the origin Mailbox's tree, i.e. have the IDs of all
+    /// dispatched messages pushed as leaves in order.
+    pub async fn message_metadata(&self, message: &HyperlaneMessage, tree: &MerkleTree) -> Vec<u8> {
+        assert_eq!(
+            message.origin, self.origin,
+            "message is not from the origin"
+        );
+
+        let index = message.nonce;
+        let (leaf, proof) = tree.generate_proof(index as usize, TREE_DEPTH);
+        assert_eq!(
+            leaf,
+            message.id(),
+            "message is not in the tree at its nonce"
+        );
+
+        self.metadata(tree.hash(), index, &proof).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{fuel_address, get_signer, recover_fuel_address};
+
+    const TEST_VALIDATOR_0_PRIVATE_KEY: &str =
+        "2ef987da35e5b389bb47cc4ec024ce0c37e5defd00de35fe61db6f50d1a858a1";
+    const TEST_VALIDATOR_1_PRIVATE_KEY: &str =
+        "411f401057d09d1d65d898ff48f775b0568e8a4cd1212e894b8b4c8820c75c3e";
+
+    // From monorepo/vectors/signedCheckpoint.json, as used by multisig-ism-metadata.
+    const TEST_MAILBOX: &str = "0x0000000000000000000000002222222222222222222222222222222222222222";
+    const TEST_ORIGIN: u32 = 1000;
+    const TEST_CHECKPOINTS: [(u8, u32, &str); 3] = [
+        (
+            0x02,
+            1,
+            "0xf5c90415788653e2c8ee94c8f10f7301f52025efb7cac767ce649132ff1384dd",
+        ),
+        (
+            0x03,
+            2,
+            "0x0f01ac543ee309d1e511ad7fbaace1ec83f264b8481724b94024f587ac3c2c4e",
+        ),
+        (
+            0x04,
+            3,
+            "0x134d65c32fac6ddf3fb9ac312552312d303b24b7b3614a9496f4de33bf412055",
+        ),
+    ];
+
+    fn checkpoint_signer() -> CheckpointSigner {
+        CheckpointSigner::new(
+            vec![
+                get_signer(TEST_VALIDATOR_0_PRIVATE_KEY),
+                get_signer(TEST_VALIDATOR_1_PRIVATE_KEY),
+            ],
+            H256::from_str(TEST_MAILBOX).unwrap(),
+            TEST_ORIGIN,
+        )
+    }
+
+    #[test]
+    fn test_checkpoint_hash_vectors() {
+        let signer = checkpoint_signer();
+        for (root_byte, index, hash) in TEST_CHECKPOINTS {
+            let checkpoint = signer.checkpoint(H256::repeat_byte(root_byte), index);
+            assert_eq!(checkpoint.signing_hash(), H256::from_str(hash).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signatures_recover_to_validators() {
+        let signer = checkpoint_signer();
+        for (root_byte, index, _) in TEST_CHECKPOINTS {
+            let root = H256::repeat_byte(root_byte);
+            let digest = signer.checkpoint_digest(root, index);
+            let signatures = signer.sign_checkpoint(root, index).await;
+
+            for (signature, private_key) in signatures
+                .iter()
+                .zip([TEST_VALIDATOR_0_PRIVATE_KEY, TEST_VALIDATOR_1_PRIVATE_KEY])
+            {
+                assert_eq!(
+                    recover_fuel_address(signature, digest),
+                    fuel_address(private_key)
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_message_metadata() {
+        let signer = checkpoint_signer();
+
+        let mut tree = MerkleTree::create(&[], TREE_DEPTH);
+        let messages = (0..3)
+            .map(|nonce| HyperlaneMessage {
+                version: 0,
+                nonce,
+                origin: TEST_ORIGIN,
+                sender: H256::repeat_byte(0xa),
+                destination: TEST_ORIGIN + 1,
+                recipient: H256::repeat_byte(0xb),
+                body: vec![nonce as u8],
+            })
+            .collect::<Vec<_>>();
+        for message in messages.iter() {
+            tree.push_leaf(message.id(), TREE_DEPTH).unwrap();
+        }
+
+        let message = &messages[1];
+        let metadata = signer.message_metadata(message, &tree).await;
+
+        // root, index, mailbox, proof and a signature per validator
+        assert_eq!(metadata.len(), 32 + 4 + 32 + 32 * TREE_DEPTH + 64 * 2);
+        assert_eq!(&metadata[..32], tree.hash().as_bytes());
+        assert_eq!(&metadata[32..36], &message.nonce.to_be_bytes());
+        assert_eq!(
+            &metadata[36..68],
+            H256::from_str(TEST_MAILBOX).unwrap().as_bytes()
+        );
+
+        let (_, proof) = tree.generate_proof(message.nonce as usize, TREE_DEPTH);
+        let encoded_proof = proof.iter().flat_map(|node| node.0).collect::<Vec<_>>();
+        assert_eq!(
+            &metadata[68..68 + 32 * TREE_DEPTH],
+            encoded_proof.as_slice()
+        );
+    }
+}
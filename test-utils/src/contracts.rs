@@ -0,0 +1,53 @@
+pub mod mailbox {
+    use fuels::prelude::abigen;
+
+    abigen!(Contract(
+        name = "Mailbox",
+        abi = "contracts/hyperlane-mailbox/out/debug/hyperlane-mailbox-abi.json"
+    ));
+}
+
+pub mod multisig_ism {
+    use fuels::prelude::abigen;
+
+    abigen!(Contract(
+        name = "MultisigIsm",
+        abi = "contracts/multisig-ism/out/debug/multisig_ism-abi.json"
+    ));
+}
+
+pub mod igp {
+    use fuels::prelude::abigen;
+
+    abigen!(Contract(
+        name = "InterchainGasPaymaster",
+        abi = "contracts/igp/interchain-gas-paymaster/out/debug/interchain-gas-paymaster-abi.json"
+    ));
+}
+
+pub mod gas_oracle {
+    use fuels::prelude::abigen;
+
+    abigen!(Contract(
+        name = "StorageGasOracle",
+        abi = "contracts/igp/storage-gas-oracle/out/debug/storage-gas-oracle-abi.json"
+    ));
+}
+
+pub mod recipient {
+    use fuels::prelude::abigen;
+
+    abigen!(Contract(
+        name = "TestMessageRecipient",
+        abi = "contracts/hyperlane-msg-recipient-test/out/debug/hyperlane-msg-recipient-test-abi.json"
+    ));
+}
+
+pub mod validator_announce {
+    use fuels::prelude::abigen;
+
+    abigen!(Contract(
+        name = "ValidatorAnnounce",
+        abi = "contracts/validator-announce/out/debug/validator-announce-abi.json"
+    ));
+}
@@ -0,0 +1,307 @@
+use ethers::types::H160;
+use fuels::{programs::logs::LogDecoder, tx::Receipt, types::errors::Error};
+use hyperlane_core::{Decode, HyperlaneMessage, H256};
+
+use crate::{
+    bits256_to_h256,
+    contracts::{igp, mailbox, validator_announce},
+};
+
+/// The log id (i.e. the value of rB in the LogData) of a dispatched message log.
+/// "hyp" in bytes
+pub const DISPATCHED_MESSAGE_LOG_ID: u64 = 0x687970u64;
+
+/// The ValidatorAnnounce's ABI, which includes the log ids of its events.
+const VALIDATOR_ANNOUNCE_ABI: &str =
+    include_str!("../../contracts/validator-announce/out/debug/validator-announce-abi.json");
+
+/// Logged by the Mailbox when a message is dispatched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DispatchIdEvent {
+    pub message_id: H256,
+}
+
+/// Logged by the Mailbox when a message is processed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessEvent {
+    pub message_id: H256,
+    pub origin: u32,
+    pub sender: H256,
+    pub recipient: H256,
+}
+
+/// Logged by the IGP when gas is paid for a message in the base asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasPaymentEvent {
+    pub message_id: H256,
+    pub gas_amount: u64,
+    pub payment: u64,
+}
+
+/// A validator's signing key, as used by the multisig ISM and ValidatorAnnounce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorKey {
+    Evm(H160),
+    Fuel(H256),
+}
+
+/// Logged by the ValidatorAnnounce when a validator announces a storage location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorAnnouncementEvent {
+    pub validator: ValidatorKey,
+    pub storage_location: String,
+}
+
+impl From<mailbox::DispatchIdEvent> for DispatchIdEvent {
+    fn from(event: mailbox::DispatchIdEvent) -> Self {
+        Self {
+            message_id: bits256_to_h256(event.message_id),
+        }
+    }
+}
+
+impl From<mailbox::ProcessEvent> for ProcessEvent {
+    fn from(event: mailbox::ProcessEvent) -> Self {
+        Self {
+            message_id: bits256_to_h256(event.message_id),
+            origin: event.origin,
+            sender: bits256_to_h256(event.sender),
+            recipient: bits256_to_h256(event.recipient),
+        }
+    }
+}
+
+impl From<igp::GasPaymentEvent> for GasPaymentEvent {
+    fn from(event: igp::GasPaymentEvent) -> Self {
+        Self {
+            message_id: bits256_to_h256(event.message_id),
+            gas_amount: event.gas_amount,
+            payment: event.payment,
+        }
+    }
+}
+
+impl From<validator_announce::ValidatorKey> for ValidatorKey {
+    fn from(key: validator_announce::ValidatorKey) -> Self {
+        match key {
+            validator_announce::ValidatorKey::Evm(address) => {
+                // EVM addresses are the last 20 bytes of the word
+                Self::Evm(H160::from_slice(&address.value().0[12..]))
+            }
+            validator_announce::ValidatorKey::Fuel(address) => Self::Fuel(H256(*address)),
+        }
+    }
+}
+
+impl From<validator_announce::ValidatorAnnouncementEvent> for ValidatorAnnouncementEvent {
+    fn from(event: validator_announce::ValidatorAnnouncementEvent) -> Self {
+        let mut bytes = event
+            .storage_location
+            .chunks
+            .iter()
+            .flat_map(|chunk| chunk.to_string().into_bytes())
+            .collect::<Vec<u8>>();
+        // Truncate the bytes rather than the string, which would panic if
+        // `len` isn't on a char boundary
+        bytes.truncate(event.storage_location.len as usize);
+        // Trim any zero padding that `len` includes
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+
+        Self {
+            validator: event.validator.into(),
+            storage_location: String::from_utf8_lossy(&bytes).into_owned(),
+        }
+    }
+}
+
+/// Decodes the messages dispatched by any Mailbox in the receipts, in the order
+/// they were dispatched.
+pub fn dispatched_messages(receipts: &[Receipt]) -> Result<Vec<HyperlaneMessage>, Error> {
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::LogData { rb, data, .. } if *rb == DISPATCHED_MESSAGE_LOG_ID => Some(data),
+            _ => None,
+        })
+        .map(|data| {
+            HyperlaneMessage::read_from(&mut data.as_slice())
+                .map_err(|err| Error::InvalidData(format!("invalid dispatched message: {err}")))
+        })
+        .collect()
+}
+
+/// Decodes the `DispatchIdEvent`s in the receipts.
+/// `log_decoder` is the Mailbox's, e.g. from `Mailbox::log_decoder`.
+pub fn dispatch_id_events(
+    log_decoder: &LogDecoder,
+    receipts: &[Receipt],
+) -> Result<Vec<DispatchIdEvent>, Error> {
+    Ok(log_decoder
+        .get_logs_with_type::<mailbox::DispatchIdEvent>(receipts)?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Decodes the `ProcessEvent`s in the receipts.
+/// `log_decoder` is the Mailbox's, e.g. from `Mailbox::log_decoder`.
+pub fn process_events(
+    log_decoder: &LogDecoder,
+    receipts: &[Receipt],
+) -> Result<Vec<ProcessEvent>, Error> {
+    Ok(log_decoder
+        .get_logs_with_type::<mailbox::ProcessEvent>(receipts)?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Decodes the `GasPaymentEvent`s in the receipts. Payments made in other
+/// assets aren't included.
+/// `log_decoder` is the IGP's, e.g. from `InterchainGasPaymaster::log_decoder`.
+pub fn gas_payment_events(
+    log_decoder: &LogDecoder,
+    receipts: &[Receipt],
+) -> Result<Vec<GasPaymentEvent>, Error> {
+    Ok(log_decoder
+        .get_logs_with_type::<igp::GasPaymentEvent>(receipts)?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Decodes the `ValidatorAnnouncementEvent`s in the receipts.
+/// `log_decoder` is the ValidatorAnnounce's, e.g. from `ValidatorAnnounce::log_decoder`.
+///
+/// The SDK matches logs by type, and a `ValidatorRevocationEvent` has the same type,
+/// so logs are first filtered by the announcement's log id in the ABI.
+pub fn validator_announcement_events(
+    log_decoder: &LogDecoder,
+    receipts: &[Receipt],
+) -> Result<Vec<ValidatorAnnouncementEvent>, Error> {
+    let log_id = logged_type_log_id(VALIDATOR_ANNOUNCE_ABI, "struct ValidatorAnnouncementEvent")?;
+    let announcements = receipts
+        .iter()
+        .filter(|receipt| matches!(receipt, Receipt::LogData { rb, .. } if *rb == log_id))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    Ok(log_decoder
+        .get_logs_with_type::<validator_announce::ValidatorAnnouncementEvent>(&announcements)?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Gets the log id (i.e. the value of rB in the LogData) of a logged type from the
+/// `loggedTypes` of a contract's ABI JSON. `type_name` is the type as named in the
+/// ABI, e.g. "struct ValidatorAnnouncementEvent".
+fn logged_type_log_id(abi: &str, type_name: &str) -> Result<u64, Error> {
+    let abi: serde_json::Value = serde_json::from_str(abi)
+        .map_err(|err| Error::InvalidData(format!("invalid ABI: {err}")))?;
+
+    let type_id = abi["types"]
+        .as_array()
+        .and_then(|types| {
+            types
+                .iter()
+                .find(|ty| ty["type"].as_str() == Some(type_name))
+        })
+        .and_then(|ty| ty["typeId"].as_u64())
+        .ok_or_else(|| Error::InvalidData(format!("type not in ABI: {type_name}")))?;
+
+    abi["loggedTypes"]
+        .as_array()
+        .and_then(|logged_types| {
+            logged_types
+                .iter()
+                .find(|logged| logged["loggedType"]["type"].as_u64() == Some(type_id))
+        })
+        .and_then(|logged| logged["logId"].as_u64())
+        .ok_or_else(|| Error::InvalidData(format!("type not logged in ABI: {type_name}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::types::{Address, Bits256, SizedAsciiString};
+
+    use super::*;
+    use crate::contracts::validator_announce::StorableString;
+
+    /// A `StorableString` of `s` as logged by the ValidatorAnnounce, with the given `len`.
+    fn storable_string(s: &str, len: u64) -> StorableString {
+        // Padded with null bytes to the max length of 1024, in 128 byte chunks
+        let padded = format!("{:\0<1024}", s);
+        let chunks = padded
+            .as_bytes()
+            .chunks(128)
+            .map(|chunk| {
+                SizedAsciiString::<128>::new(std::str::from_utf8(chunk).unwrap().to_string())
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        StorableString {
+            len,
+            chunks: chunks.try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_validator_announcement_event_from_abi() {
+        let storage_location = "file://some/path/to/storage";
+
+        // With the exact length, and with a length that includes the padding
+        for len in [storage_location.len() as u64, 1024] {
+            let event = validator_announce::ValidatorAnnouncementEvent {
+                validator: validator_announce::ValidatorKey::Fuel(Address::new([0xab; 32])),
+                storage_location: storable_string(storage_location, len),
+            };
+            assert_eq!(
+                ValidatorAnnouncementEvent::from(event),
+                ValidatorAnnouncementEvent {
+                    validator: ValidatorKey::Fuel(H256::repeat_byte(0xab)),
+                    storage_location: storage_location.to_string(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_logged_type_log_id() {
+        let abi = r#"{
+            "types": [
+                { "typeId": 0, "type": "struct ValidatorAnnouncementEvent" },
+                { "typeId": 1, "type": "struct ValidatorRevocationEvent" }
+            ],
+            "loggedTypes": [
+                { "logId": 3, "loggedType": { "name": "", "type": 1 } },
+                { "logId": 5, "loggedType": { "name": "", "type": 0 } }
+            ]
+        }"#;
+        assert_eq!(
+            logged_type_log_id(abi, "struct ValidatorAnnouncementEvent").unwrap(),
+            5
+        );
+        assert_eq!(
+            logged_type_log_id(abi, "struct ValidatorRevocationEvent").unwrap(),
+            3
+        );
+        assert!(logged_type_log_id(abi, "struct Unknown").is_err());
+
+        // The announcement and revocation are logged with distinct ids
+        assert_ne!(
+            logged_type_log_id(VALIDATOR_ANNOUNCE_ABI, "struct ValidatorAnnouncementEvent")
+                .unwrap(),
+            logged_type_log_id(VALIDATOR_ANNOUNCE_ABI, "struct ValidatorRevocationEvent").unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_evm_validator_key_from_abi() {
+        let address = H160::repeat_byte(0xcd);
+        let key = validator_announce::ValidatorKey::Evm(Bits256(H256::from(address).0).into());
+        assert_eq!(ValidatorKey::from(key), ValidatorKey::Evm(address));
+    }
+}
@@ -1,13 +1,36 @@
-use std::str::FromStr;
+mod announcement;
+mod checkpoint;
+pub mod contracts;
+mod events;
+pub mod network;
+mod revert;
+
+pub use announcement::{
+    announcement_digest, announcement_domain_hash, fuel_announcement_digest, sign_announcement,
+    sign_fuel_announcement,
+};
+pub use checkpoint::CheckpointSigner;
+pub use events::{
+    dispatch_id_events, dispatched_messages, gas_payment_events, process_events,
+    validator_announcement_events, DispatchIdEvent, GasPaymentEvent, ProcessEvent,
+    ValidatorAnnouncementEvent, ValidatorKey, DISPATCHED_MESSAGE_LOG_ID,
+};
+pub use network::LocalHyperlaneNetwork;
+pub use revert::{decode_revert_reason, RevertReason};
+
+use std::{future::Future, str::FromStr};
 
 use ethers::signers::Signer;
 use ethers::types::{Signature, H256, U256};
 use fuels::types::B512;
 use fuels::{
-    accounts::{fuel_crypto::SecretKey, WalletUnlocked},
+    accounts::{
+        fuel_crypto::{Message, PublicKey, SecretKey, Signature as FuelSignature},
+        WalletUnlocked,
+    },
     prelude::{Account, Bech32Address, TxParameters},
-    tx::{AssetId, Receipt},
-    types::{errors::Error, Bits256, EvmAddress},
+    tx::AssetId,
+    types::{errors::Error, Address, Bits256, EvmAddress},
 };
 use hyperlane_core::HyperlaneSignerExt;
 use hyperlane_core::Signable;
@@ -26,6 +49,12 @@ pub fn evm_address(signer: &Signers) -> EvmAddress {
     h256_to_bits256(signer.address().into()).into()
 }
 
+/// The native Fuel address of a secp256k1 private key, i.e. the sha256 hash of its public key.
+pub fn fuel_address(private_key: &str) -> Address {
+    let secret_key = SecretKey::from_str(private_key).unwrap();
+    Address::from(*PublicKey::from(&secret_key).hash())
+}
+
 pub fn zero_address() -> EvmAddress {
     EvmAddress::from(Bits256([0u8; 32]))
 }
@@ -59,57 +88,23 @@ pub fn signature_to_compact(signature: &Signature) -> [u8; 64] {
     compact
 }
 
+/// Recovers the native Fuel address of the signer of a digest from a compact signature,
+/// the same way `ec_recover_address` does.
+pub fn recover_fuel_address(signature: &B512, digest: H256) -> Address {
+    let mut compact = [0u8; 64];
+    compact[..32].copy_from_slice(&signature.bytes[0].0);
+    compact[32..].copy_from_slice(&signature.bytes[1].0);
+    let public_key = FuelSignature::from_bytes(compact)
+        .recover(&Message::from_bytes(digest.0))
+        .unwrap();
+    Address::from(*public_key.hash())
+}
+
 pub async fn sign_compact<T: Signable + std::marker::Send>(signer: &Signers, signable: T) -> B512 {
     let signed = signer.sign(signable).await.unwrap();
     return B512::try_from(signature_to_compact(&signed.signature).as_slice()).unwrap();
 }
 
-// TODO: figure out why this has different behavior than get_revert_string
-pub fn get_revert_reason(call_error: Error) -> String {
-    let reason = if let Error::RevertTransactionError { reason, .. } = call_error {
-        reason
-    } else {
-        panic!(
-            "Error is not a RevertTransactionError. Error: {:?}",
-            call_error
-        );
-    };
-
-    return reason;
-}
-
-// Given an Error from a call or simulation, returns the revert reason.
-// Panics if it's unable to find the revert reason.
-pub fn get_revert_string(call_error: Error) -> String {
-    let receipts = if let Error::RevertTransactionError { receipts, .. } = call_error {
-        receipts
-    } else {
-        panic!(
-            "Error is not a RevertTransactionError. Error: {:?}",
-            call_error
-        );
-    };
-
-    // The receipts will be:
-    // [any prior receipts..., LogData with reason, Revert, ScriptResult]
-    // We want the LogData with the reason, which is utf-8 encoded as the `data`.
-
-    let revert_reason_receipt = &receipts[receipts.len() - 3];
-    let data = if let Receipt::LogData { data, .. } = revert_reason_receipt {
-        data
-    } else {
-        panic!(
-            "Expected LogData receipt. Receipt: {:?}",
-            revert_reason_receipt
-        );
-    };
-
-    // Null bytes `\0` will be padded to the end of the revert string, so we remove them.
-    let data: Vec<u8> = data.iter().cloned().filter(|byte| *byte != b'\0').collect();
-
-    String::from_utf8(data).unwrap()
-}
-
 pub async fn funded_wallet_with_private_key(
     funder: &WalletUnlocked,
     private_key: &str,
@@ -130,6 +125,33 @@ pub async fn funded_wallet_with_private_key(
     Ok(wallet)
 }
 
+/// Gets all items of a paginated view by requesting pages of `page_size` items
+/// at increasing offsets until `count` items have been covered.
+/// `get_page` is called with the offset and limit of each page.
+/// Pages may contain fewer items than the limit, e.g. if the view filters items.
+/// Returns an error if `page_size` is zero.
+pub async fn get_all_pages<T, F, Fut>(
+    count: u64,
+    page_size: u64,
+    mut get_page: F,
+) -> Result<Vec<T>, Error>
+where
+    F: FnMut(u64, u64) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, Error>>,
+{
+    if page_size == 0 {
+        return Err(Error::InvalidData("page size must be nonzero".into()));
+    }
+
+    let mut items = vec![];
+    let mut offset = 0;
+    while offset < count {
+        items.extend(get_page(offset, page_size).await?);
+        offset += page_size;
+    }
+    Ok(items)
+}
+
 /// Kludge to deserialize into Bits256
 pub fn deserialize_bits_256<'de, D>(deserializer: D) -> Result<Bits256, D::Error>
 where
@@ -188,3 +210,46 @@ pub fn encode_multisig_metadata(
     }
     bytes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calls `get_all_pages` for `count` sequential items, returning the items
+    /// and the (offset, limit) of each page requested.
+    async fn get_all_pages_of(
+        count: u64,
+        page_size: u64,
+    ) -> (Result<Vec<u64>, Error>, Vec<(u64, u64)>) {
+        let mut requested = vec![];
+        let items = get_all_pages(count, page_size, |offset, limit| {
+            requested.push((offset, limit));
+            let end = count.min(offset + limit);
+            async move { Ok((offset..end).collect()) }
+        })
+        .await;
+        (items, requested)
+    }
+
+    #[tokio::test]
+    async fn test_get_all_pages_rejects_zero_page_size() {
+        let (items, requested) = get_all_pages_of(5, 0).await;
+        assert!(matches!(items, Err(Error::InvalidData(_))));
+        assert_eq!(requested, vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_pages_ends_with_partial_page() {
+        let (items, requested) = get_all_pages_of(7, 3).await;
+        assert_eq!(items.unwrap(), (0..7).collect::<Vec<_>>());
+        assert_eq!(requested, vec![(0, 3), (3, 3), (6, 3)]);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_pages_exact_multiple_of_page_size() {
+        let (items, requested) = get_all_pages_of(6, 3).await;
+        assert_eq!(items.unwrap(), (0..6).collect::<Vec<_>>());
+        // No extra empty page is requested
+        assert_eq!(requested, vec![(0, 3), (3, 3)]);
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::VecDeque;
+
+use fuels::{
+    prelude::*,
+    programs::call_response::FuelCallResponse,
+    tx::{ContractId, Receipt, Salt},
+    types::{errors::Error, Bits256, Bytes, Identity},
+};
+use hyperlane_core::{
+    accumulator::{merkle::MerkleTree, TREE_DEPTH},
+    Encode, HyperlaneMessage, H256,
+};
+use hyperlane_ethereum::Signers;
+
+use crate::{
+    bits256_to_h256,
+    contracts::{
+        gas_oracle::{RemoteGasData, RemoteGasDataConfig, StorageGasOracle, U128},
+        igp::InterchainGasPaymaster,
+        mailbox::{Mailbox, MailboxConfigurables},
+        multisig_ism::{MultisigIsm, ValidatorKey},
+        recipient::TestMessageRecipient,
+    },
+    dispatched_messages, evm_address, h256_to_bits256, CheckpointSigner,
+};
+
+/// A token exchange rate of 1.0, i.e. remote gas is priced the same as local gas.
+const TOKEN_EXCHANGE_RATE_SCALE: u64 = 10_000_000_000_000_000_000;
+const BASE_ASSET_DECIMALS: u8 = 9;
+
+/// The gas limit of relayed `process` transactions, which verify the
+/// merkle proof and each validator signature.
+const PROCESS_GAS_LIMIT: u64 = 10_000_000;
+
+/// Gets the path of a build artifact in the `contracts` directory.
+fn contract_artifact(path: &str) -> String {
+    format!("{}/../contracts/{}", env!("CARGO_MANIFEST_DIR"), path)
+}
+
+/// Deploys a contract from its `out/debug` build artifacts.
+async fn deploy(
+    wallet: &WalletUnlocked,
+    package_dir: &str,
+    package_name: &str,
+    configuration: DeployConfiguration,
+) -> Result<Bech32ContractId, Error> {
+    Contract::deploy(
+        &contract_artifact(&format!("{package_dir}/out/debug/{package_name}.bin")),
+        wallet,
+        configuration.set_storage_configuration(StorageConfiguration::new(
+            contract_artifact(&format!(
+                "{package_dir}/out/debug/{package_name}-storage_slots.json"
+            )),
+            vec![],
+        )),
+    )
+    .await
+}
+
+/// The contracts of a single chain in a `LocalHyperlaneNetwork`.
+pub struct LocalChain {
+    pub domain: u32,
+    pub mailbox: Mailbox<WalletUnlocked>,
+    /// The Mailbox's default ISM, which enrolls the network's validators for every other chain.
+    pub multisig_ism: MultisigIsm<WalletUnlocked>,
+    /// Quotes gas payments to every other chain using `gas_oracle`.
+    pub igp: InterchainGasPaymaster<WalletUnlocked>,
+    pub gas_oracle: StorageGasOracle<WalletUnlocked>,
+    pub recipient: TestMessageRecipient<WalletUnlocked>,
+    /// Mirrors the Mailbox's merkle tree, as a validator would.
+    tree: MerkleTree,
+    /// Dispatched messages that have not yet been relayed.
+    pending: VecDeque<HyperlaneMessage>,
+}
+
+impl LocalChain {
+    async fn deploy(wallet: &WalletUnlocked, domain: u32) -> Result<Self, Error> {
+        // Contracts that are deployed to every chain are salted by domain
+        // so they have distinct contract IDs.
+        let mut salt = [0u8; 32];
+        salt[28..].copy_from_slice(&domain.to_be_bytes());
+        let configuration = || DeployConfiguration::default().set_salt(Salt::from(salt));
+        let owner = Identity::Address(wallet.address().into());
+
+        let mailbox_id = deploy(
+            wallet,
+            "hyperlane-mailbox",
+            "hyperlane-mailbox",
+            configuration().set_configurables(MailboxConfigurables::new().set_LOCAL_DOMAIN(domain)),
+        )
+        .await?;
+        let mailbox = Mailbox::new(mailbox_id, wallet.clone());
+        mailbox
+            .methods()
+            .set_ownership(owner.clone())
+            .call()
+            .await?;
+
+        let multisig_ism_id =
+            deploy(wallet, "multisig-ism", "multisig_ism", configuration()).await?;
+        let multisig_ism = MultisigIsm::new(multisig_ism_id.clone(), wallet.clone());
+        multisig_ism
+            .methods()
+            .set_ownership(owner.clone())
+            .call()
+            .await?;
+        mailbox
+            .methods()
+            .set_default_ism(ContractId::from(&multisig_ism_id))
+            .call()
+            .await?;
+
+        let igp_id = deploy(
+            wallet,
+            "igp/interchain-gas-paymaster",
+            "interchain-gas-paymaster",
+            configuration(),
+        )
+        .await?;
+        let igp = InterchainGasPaymaster::new(igp_id, wallet.clone());
+        igp.methods().set_ownership(owner.clone()).call().await?;
+
+        let gas_oracle_id = deploy(
+            wallet,
+            "igp/storage-gas-oracle",
+            "storage-gas-oracle",
+            configuration(),
+        )
+        .await?;
+        let gas_oracle = StorageGasOracle::new(gas_oracle_id, wallet.clone());
+        gas_oracle.methods().set_ownership(owner).call().await?;
+
+        let recipient_id = deploy(
+            wallet,
+            "hyperlane-msg-recipient-test",
+            "hyperlane-msg-recipient-test",
+            configuration(),
+        )
+        .await?;
+        let recipient = TestMessageRecipient::new(recipient_id, wallet.clone());
+
+        Ok(Self {
+            domain,
+            mailbox,
+            multisig_ism,
+            igp,
+            gas_oracle,
+            recipient,
+            tree: MerkleTree::create(&[], TREE_DEPTH),
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Enrolls the validators and sets the threshold for the remote domain in the
+    /// multisig ISM, and prices gas to the remote domain the same as local gas.
+    async fn connect(
+        &self,
+        remote_domain: u32,
+        validators: &[Signers],
+        threshold: u8,
+    ) -> Result<(), Error> {
+        let validators = validators
+            .iter()
+            .map(|validator| ValidatorKey::Evm(evm_address(validator)))
+            .collect::<Vec<_>>();
+        self.multisig_ism
+            .methods()
+            .enroll_validators(vec![remote_domain], vec![validators])
+            .call()
+            .await?;
+        self.multisig_ism
+            .methods()
+            .set_threshold(remote_domain, threshold)
+            .call()
+            .await?;
+
+        self.gas_oracle
+            .methods()
+            .set_remote_gas_data_configs(vec![RemoteGasDataConfig {
+                domain: remote_domain,
+                remote_gas_data: RemoteGasData {
+                    token_exchange_rate: U128 {
+                        upper: 0,
+                        lower: TOKEN_EXCHANGE_RATE_SCALE,
+                    },
+                    gas_price: U128 { upper: 0, lower: 1 },
+                    token_decimals: BASE_ASSET_DECIMALS,
+                },
+            }])
+            .call()
+            .await?;
+        self.igp
+            .methods()
+            .set_gas_oracle(
+                remote_domain,
+                Bits256(*self.gas_oracle.contract_id().hash()),
+            )
+            .call()
+            .await?;
+        Ok(())
+    }
+
+    /// The Mailbox's contract ID, as used in message senders and checkpoints.
+    pub fn mailbox_address(&self) -> H256 {
+        H256(*self.mailbox.contract_id().hash())
+    }
+
+    /// The test recipient's contract ID, as used in message recipients.
+    pub fn recipient_address(&self) -> H256 {
+        H256(*self.recipient.contract_id().hash())
+    }
+
+    /// Records the messages dispatched by the Mailbox in the receipts.
+    fn observe(&mut self, receipts: &[Receipt]) -> Result<Vec<HyperlaneMessage>, Error> {
+        let messages = dispatched_messages(receipts)?
+            .into_iter()
+            .filter(|message| message.origin == self.domain)
+            .collect::<Vec<_>>();
+
+        for message in messages.iter() {
+            self.tree.push_leaf(message.id(), TREE_DEPTH).unwrap();
+            self.pending.push_back(message.clone());
+        }
+        Ok(messages)
+    }
+}
+
+/// A local Hyperlane network of two chains in a single test node, each with
+/// a Mailbox, a multisig ISM, an IGP and a test recipient.
+///
+/// The network acts as the validators and relayer: validators sign checkpoints
+/// of the origin Mailbox's merkle tree, and the relayer processes each dispatched
+/// message on its destination Mailbox, verified by the destination's multisig ISM.
+///
+/// Messages are only relayed if they are dispatched via `dispatch`, or their
+/// receipts are passed to `observe`.
+pub struct LocalHyperlaneNetwork {
+    pub wallet: WalletUnlocked,
+    chains: Vec<LocalChain>,
+    validators: Vec<Signers>,
+}
+
+impl LocalHyperlaneNetwork {
+    /// Launches a test node and deploys a chain for each domain. Each chain's
+    /// multisig ISM enrolls all `validators` with the `threshold` for the other chain.
+    pub async fn new(
+        domains: [u32; 2],
+        validators: Vec<Signers>,
+        threshold: u8,
+    ) -> Result<Self, Error> {
+        let mut wallets = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new(
+                Some(1),             /* Single wallet */
+                Some(1),             /* Single coin (UTXO) */
+                Some(1_000_000_000), /* Amount per coin */
+            ),
+            None,
+            None,
+        )
+        .await;
+        let wallet = wallets.pop().unwrap();
+
+        let mut chains = Vec::with_capacity(domains.len());
+        for domain in domains {
+            chains.push(LocalChain::deploy(&wallet, domain).await?);
+        }
+        for chain in chains.iter() {
+            for remote_domain in domains.iter().filter(|d| **d != chain.domain) {
+                chain
+                    .connect(*remote_domain, &validators, threshold)
+                    .await?;
+            }
+        }
+
+        Ok(Self {
+            wallet,
+            chains,
+            validators,
+        })
+    }
+
+    /// Gets the chain of the domain. Panics if the domain isn't in the network.
+    pub fn chain(&self, domain: u32) -> &LocalChain {
+        self.chains
+            .iter()
+            .find(|chain| chain.domain == domain)
+            .expect("domain not in network")
+    }
+
+    fn chain_mut(&mut self, domain: u32) -> &mut LocalChain {
+        self.chains
+            .iter_mut()
+            .find(|chain| chain.domain == domain)
+            .expect("domain not in network")
+    }
+
+    /// Dispatches a message from the origin chain's Mailbox, returning the dispatched message.
+    pub async fn dispatch(
+        &mut self,
+        origin: u32,
+        destination: u32,
+        recipient: H256,
+        body: Vec<u8>,
+    ) -> Result<HyperlaneMessage, Error> {
+        let call = self
+            .chain(origin)
+            .mailbox
+            .methods()
+            .dispatch(destination, h256_to_bits256(recipient), Bytes(body))
+            .call()
+            .await?;
+        self.observe(origin, &call.receipts)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::InvalidData("no message dispatched".into()))
+    }
+
+    /// Records the messages dispatched by the origin chain's Mailbox in the receipts,
+    /// e.g. from a contract call that dispatches messages, so they can be relayed.
+    pub fn observe(
+        &mut self,
+        origin: u32,
+        receipts: &[Receipt],
+    ) -> Result<Vec<HyperlaneMessage>, Error> {
+        self.chain_mut(origin).observe(receipts)
+    }
+
+    /// Pays the origin chain's IGP for the gas to process the message on its destination.
+    pub async fn pay_for_gas(
+        &self,
+        message: &HyperlaneMessage,
+        gas_amount: u64,
+    ) -> Result<FuelCallResponse<()>, Error> {
+        let igp = &self.chain(message.origin).igp;
+        let quote = igp
+            .methods()
+            .quote_gas_payment(message.destination, gas_amount)
+            .simulate()
+            .await?
+            .value;
+        igp.methods()
+            .pay_for_gas(
+                h256_to_bits256(message.id()),
+                message.destination,
+                gas_amount,
+                Identity::Address(self.wallet.address().into()),
+            )
+            .call_params(
+                CallParameters::default()
+                    .set_asset_id(BASE_ASSET_ID)
+                    .set_amount(quote),
+            )?
+            .estimate_tx_dependencies(Some(5))
+            .await?
+            .call()
+            .await
+    }
+
+    /// Relays all pending messages to their destinations, in the order they were
+    /// dispatched. Returns the relayed messages. If a message fails to be processed,
+    /// it and any later messages from its origin remain pending, messages from other
+    /// origins are still relayed, and the first error is returned.
+    pub async fn relay(&mut self) -> Result<Vec<HyperlaneMessage>, Error> {
+        let mut relayed = vec![];
+        let mut first_err = None;
+        for i in 0..self.chains.len() {
+            while let Some(message) = self.chains[i].pending.pop_front() {
+                // Failed messages are left pending so they can be retried
+                if let Err(err) = self.process(&message).await {
+                    self.chains[i].pending.push_front(message);
+                    first_err.get_or_insert(err);
+                    break;
+                }
+                relayed.push(message);
+            }
+        }
+        match first_err {
+            Some(err) => Err(err),
+            None => Ok(relayed),
+        }
+    }
+
+    /// Signs a checkpoint of the origin Mailbox's tree with the validators, and
+    /// processes the message on the destination Mailbox.
+    async fn process(&self, message: &HyperlaneMessage) -> Result<(), Error> {
+        let origin = self.chain(message.origin);
+
+        // Validators only sign checkpoints of the origin Mailbox's tree
+        let (root, _) = origin
+            .mailbox
+            .methods()
+            .latest_checkpoint()
+            .simulate()
+            .await?
+            .value;
+        if bits256_to_h256(root) != origin.tree.hash() {
+            return Err(Error::InvalidData(
+                "tree doesn't mirror the origin Mailbox".into(),
+            ));
+        }
+
+        let metadata = CheckpointSigner::new(
+            self.validators.clone(),
+            origin.mailbox_address(),
+            origin.domain,
+        )
+        .message_metadata(message, &origin.tree)
+        .await;
+
+        let destination = self.chain(message.destination);
+        let recipient = Bech32ContractId::from(ContractId::from(message.recipient.0));
+        destination
+            .mailbox
+            .methods()
+            .process(Bytes(metadata), Bytes(message.to_vec()))
+            .set_contract_ids(&[destination.multisig_ism.contract_id().clone(), recipient])
+            .tx_params(TxParameters::default().set_gas_limit(PROCESS_GAS_LIMIT))
+            .call()
+            .await?;
+        Ok(())
+    }
+}
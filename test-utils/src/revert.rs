@@ -0,0 +1,141 @@
+use fuels::{tx::Receipt, types::errors::Error};
+
+/// The revert code of a failed `require`. Matches `FAILED_REQUIRE_SIGNAL` in std::error_signals.
+const FAILED_REQUIRE_SIGNAL: u64 = 0xffff_ffff_ffff_0000;
+
+/// The ASCII signals the contracts revert with, e.g. "merkle" when the merkle tree is full.
+/// Any other code is a panic, even if its bytes happen to be printable ASCII.
+const KNOWN_SIGNALS: [&str; 1] = ["merkle"];
+
+/// Why a transaction reverted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevertReason {
+    /// A failed `require`. The reason is the logged string, e.g. "!merkle",
+    /// or the SDK's decoding of any other logged type, e.g. "NotOwner".
+    Require(String),
+    /// A `revert` with a code, e.g. `revert(0)` when unwrapping a `None`,
+    /// or the revert code of a failed `assert`.
+    Panic(u64),
+    /// A `revert` with one of the known ASCII encoded codes, e.g.
+    /// `revert(0x6d65726b6c65)` is the signal "merkle" when the merkle tree is full.
+    Signal(String),
+    /// Any other failure, e.g. a VM panic such as running out of gas.
+    /// The reason is as reported by the SDK.
+    Unknown(String),
+}
+
+impl RevertReason {
+    /// A failed `require` with the reason.
+    pub fn require(reason: &str) -> Self {
+        Self::Require(reason.to_string())
+    }
+
+    /// A `revert` with the ASCII signal.
+    pub fn signal(signal: &str) -> Self {
+        Self::Signal(signal.to_string())
+    }
+}
+
+/// Decodes why a call or simulation reverted.
+/// Returns the error back if it isn't a `RevertTransactionError`.
+pub fn decode_revert_reason(call_error: Error) -> Result<RevertReason, Error> {
+    let (reason, receipts) = match call_error {
+        Error::RevertTransactionError {
+            reason, receipts, ..
+        } => (reason, receipts),
+        _ => return Err(call_error),
+    };
+
+    // The Revert receipt is usually followed by a ScriptResult, and may be
+    // preceded by any number of receipts, so search for it from the end.
+    let revert = receipts
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, receipt)| match receipt {
+            Receipt::Revert { id, ra, .. } => Some((index, *id, *ra)),
+            _ => None,
+        });
+    let (revert_index, revert_id, code) = match revert {
+        Some(revert) => revert,
+        None => return Ok(RevertReason::Unknown(reason)),
+    };
+
+    if code != FAILED_REQUIRE_SIGNAL {
+        return Ok(decode_signal(code).map_or(RevertReason::Panic(code), RevertReason::Signal));
+    }
+
+    // The reason is the last thing logged by the reverting contract.
+    let logged_string = receipts[..revert_index]
+        .iter()
+        .rev()
+        .find(|receipt| match receipt {
+            Receipt::LogData { id, .. } | Receipt::Log { id, .. } => *id == revert_id,
+            _ => false,
+        })
+        .and_then(|receipt| match receipt {
+            Receipt::LogData { data, .. } => decode_string(data),
+            _ => None,
+        });
+
+    Ok(RevertReason::Require(logged_string.unwrap_or(reason)))
+}
+
+/// Decodes a revert code as a known ASCII signal, e.g. 0x6d65726b6c65 as "merkle".
+/// Returns None if the code isn't a known signal.
+fn decode_signal(code: u64) -> Option<String> {
+    KNOWN_SIGNALS
+        .iter()
+        .find(|signal| encode_signal(signal) == code)
+        .map(|signal| signal.to_string())
+}
+
+/// Encodes an ASCII signal of at most 8 bytes as a revert code.
+fn encode_signal(signal: &str) -> u64 {
+    signal
+        .bytes()
+        .fold(0, |code, byte| (code << 8) | u64::from(byte))
+}
+
+/// Decodes logged data as a string. Strings are padded with null bytes to a
+/// whole number of words, which are removed. Returns None if the data isn't
+/// a non-empty string, e.g. if another type was logged.
+fn decode_string(data: &[u8]) -> Option<String> {
+    let end = data.iter().rposition(|byte| *byte != 0)? + 1;
+    let string = std::str::from_utf8(&data[..end]).ok()?;
+    (!string.chars().any(char::is_control)).then(|| string.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_signal() {
+        assert_eq!(decode_signal(0x6d65726b6c65), Some("merkle".to_string()));
+
+        assert_eq!(decode_signal(0), None);
+        assert_eq!(decode_signal(1), None);
+        assert_eq!(decode_signal(FAILED_REQUIRE_SIGNAL), None);
+        // Printable ASCII codes that aren't known signals, e.g. `revert(42)`
+        assert_eq!(decode_signal(0x41), None);
+        assert_eq!(decode_signal(42), None);
+        assert_eq!(decode_signal(0x6d65726b), None);
+    }
+
+    #[test]
+    fn test_decode_string() {
+        assert_eq!(decode_string(b"!merkle\0"), Some("!merkle".to_string()));
+        assert_eq!(
+            decode_string(b"contract is paused\0\0\0\0\0\0"),
+            Some("contract is paused".to_string())
+        );
+        assert_eq!(decode_string(b"delivered"), Some("delivered".to_string()));
+
+        assert_eq!(decode_string(&[]), None);
+        assert_eq!(decode_string(&[0u8; 8]), None);
+        // e.g. a logged enum variant
+        assert_eq!(decode_string(&[0, 0, 0, 0, 0, 0, 0, 1]), None);
+        assert_eq!(decode_string(&[0xff, 0xfe]), None);
+    }
+}
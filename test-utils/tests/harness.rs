@@ -0,0 +1,264 @@
+use fuels::types::Bytes;
+use hyperlane_core::H256;
+use test_utils::{
+    bits256_to_h256, dispatch_id_events, dispatched_messages, gas_payment_events, get_signer,
+    h256_to_bits256, DispatchIdEvent, LocalHyperlaneNetwork,
+};
+
+const TEST_ORIGIN_DOMAIN: u32 = 1000;
+const TEST_DESTINATION_DOMAIN: u32 = 2000;
+const TEST_GAS_AMOUNT: u64 = 300000;
+
+const TEST_VALIDATOR_0_PRIVATE_KEY: &str =
+    "2ef987da35e5b389bb47cc4ec024ce0c37e5defd00de35fe61db6f50d1a858a1";
+const TEST_VALIDATOR_1_PRIVATE_KEY: &str =
+    "411f401057d09d1d65d898ff48f775b0568e8a4cd1212e894b8b4c8820c75c3e";
+
+async fn get_network() -> LocalHyperlaneNetwork {
+    LocalHyperlaneNetwork::new(
+        [TEST_ORIGIN_DOMAIN, TEST_DESTINATION_DOMAIN],
+        vec![
+            get_signer(TEST_VALIDATOR_0_PRIVATE_KEY),
+            get_signer(TEST_VALIDATOR_1_PRIVATE_KEY),
+        ],
+        2,
+    )
+    .await
+    .unwrap()
+}
+
+async fn is_delivered(network: &LocalHyperlaneNetwork, domain: u32, message_id: H256) -> bool {
+    network
+        .chain(domain)
+        .mailbox
+        .methods()
+        .delivered(h256_to_bits256(message_id))
+        .simulate()
+        .await
+        .unwrap()
+        .value
+}
+
+// ============ dispatch ============
+
+#[tokio::test]
+async fn test_dispatch() {
+    let mut network = get_network().await;
+    let recipient = network.chain(TEST_DESTINATION_DOMAIN).recipient_address();
+
+    let message = network
+        .dispatch(
+            TEST_ORIGIN_DOMAIN,
+            TEST_DESTINATION_DOMAIN,
+            recipient,
+            vec![10u8; 100],
+        )
+        .await
+        .unwrap();
+
+    let origin = network.chain(TEST_ORIGIN_DOMAIN);
+    assert_eq!(message.nonce, 0);
+    assert_eq!(message.origin, TEST_ORIGIN_DOMAIN);
+    assert_eq!(message.sender, H256(*network.wallet.address().hash()));
+    assert_eq!(message.destination, TEST_DESTINATION_DOMAIN);
+    assert_eq!(message.recipient, recipient);
+    assert_eq!(message.body, vec![10u8; 100]);
+
+    let count = origin
+        .mailbox
+        .methods()
+        .count()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(count, 1);
+}
+
+#[tokio::test]
+async fn test_dispatch_events() {
+    let network = get_network().await;
+    let mailbox = &network.chain(TEST_ORIGIN_DOMAIN).mailbox;
+    let recipient = network.chain(TEST_DESTINATION_DOMAIN).recipient_address();
+
+    let call = mailbox
+        .methods()
+        .dispatch(
+            TEST_DESTINATION_DOMAIN,
+            h256_to_bits256(recipient),
+            Bytes(vec![10u8; 100]),
+        )
+        .call()
+        .await
+        .unwrap();
+
+    let messages = dispatched_messages(&call.receipts).unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].recipient, recipient);
+    assert_eq!(bits256_to_h256(call.value), messages[0].id());
+
+    let events = dispatch_id_events(&mailbox.log_decoder(), &call.receipts).unwrap();
+    assert_eq!(
+        events,
+        vec![DispatchIdEvent {
+            message_id: messages[0].id(),
+        }]
+    );
+}
+
+// ============ pay_for_gas ============
+
+#[tokio::test]
+async fn test_pay_for_gas() {
+    let mut network = get_network().await;
+    let recipient = network.chain(TEST_DESTINATION_DOMAIN).recipient_address();
+
+    let message = network
+        .dispatch(
+            TEST_ORIGIN_DOMAIN,
+            TEST_DESTINATION_DOMAIN,
+            recipient,
+            vec![10u8; 100],
+        )
+        .await
+        .unwrap();
+    let call = network
+        .pay_for_gas(&message, TEST_GAS_AMOUNT)
+        .await
+        .unwrap();
+
+    let igp = &network.chain(TEST_ORIGIN_DOMAIN).igp;
+    let events = gas_payment_events(&igp.log_decoder(), &call.receipts).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].message_id, message.id());
+    assert_eq!(events[0].gas_amount, TEST_GAS_AMOUNT);
+
+    let gas_paid = network
+        .chain(TEST_ORIGIN_DOMAIN)
+        .igp
+        .methods()
+        .gas_paid(h256_to_bits256(message.id()))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(gas_paid, TEST_GAS_AMOUNT);
+}
+
+// ============ relay ============
+
+#[tokio::test]
+async fn test_relay() {
+    let mut network = get_network().await;
+    let recipient = network.chain(TEST_DESTINATION_DOMAIN).recipient_address();
+
+    let message = network
+        .dispatch(
+            TEST_ORIGIN_DOMAIN,
+            TEST_DESTINATION_DOMAIN,
+            recipient,
+            vec![10u8; 100],
+        )
+        .await
+        .unwrap();
+    network
+        .pay_for_gas(&message, TEST_GAS_AMOUNT)
+        .await
+        .unwrap();
+
+    let relayed = network.relay().await.unwrap();
+    assert_eq!(relayed, vec![message.clone()]);
+
+    assert!(is_delivered(&network, TEST_DESTINATION_DOMAIN, message.id()).await);
+    let handled = network
+        .chain(TEST_DESTINATION_DOMAIN)
+        .recipient
+        .methods()
+        .handled()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert!(handled);
+
+    // Nothing left to relay
+    assert!(network.relay().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_relay_in_both_directions() {
+    let mut network = get_network().await;
+
+    let mut messages = vec![];
+    for (origin, destination) in [
+        (TEST_ORIGIN_DOMAIN, TEST_DESTINATION_DOMAIN),
+        (TEST_DESTINATION_DOMAIN, TEST_ORIGIN_DOMAIN),
+        (TEST_ORIGIN_DOMAIN, TEST_DESTINATION_DOMAIN),
+        (TEST_DESTINATION_DOMAIN, TEST_ORIGIN_DOMAIN),
+    ] {
+        let recipient = network.chain(destination).recipient_address();
+        messages.push(
+            network
+                .dispatch(origin, destination, recipient, vec![messages.len() as u8])
+                .await
+                .unwrap(),
+        );
+    }
+
+    let relayed = network.relay().await.unwrap();
+    assert_eq!(relayed.len(), messages.len());
+    for message in messages.iter() {
+        assert!(relayed.contains(message));
+        assert!(is_delivered(&network, message.destination, message.id()).await);
+    }
+}
+
+#[tokio::test]
+async fn test_relay_retries_failed_messages() {
+    let mut network = get_network().await;
+    let recipient = network.chain(TEST_DESTINATION_DOMAIN).recipient_address();
+
+    let message = network
+        .dispatch(
+            TEST_ORIGIN_DOMAIN,
+            TEST_DESTINATION_DOMAIN,
+            recipient,
+            vec![10u8; 100],
+        )
+        .await
+        .unwrap();
+
+    // A message in the other direction, to a recipient that doesn't revert
+    let origin_recipient = network.chain(TEST_ORIGIN_DOMAIN).recipient_address();
+    let other_message = network
+        .dispatch(
+            TEST_DESTINATION_DOMAIN,
+            TEST_ORIGIN_DOMAIN,
+            origin_recipient,
+            vec![20u8; 100],
+        )
+        .await
+        .unwrap();
+
+    let destination_recipient = &network.chain(TEST_DESTINATION_DOMAIN).recipient;
+    destination_recipient
+        .methods()
+        .set_should_revert(true)
+        .call()
+        .await
+        .unwrap();
+    assert!(network.relay().await.is_err());
+    assert!(!is_delivered(&network, TEST_DESTINATION_DOMAIN, message.id()).await);
+    // Messages from other origins are still relayed
+    assert!(is_delivered(&network, TEST_ORIGIN_DOMAIN, other_message.id()).await);
+
+    let destination_recipient = &network.chain(TEST_DESTINATION_DOMAIN).recipient;
+    destination_recipient
+        .methods()
+        .set_should_revert(false)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(network.relay().await.unwrap(), vec![message.clone()]);
+    assert!(is_delivered(&network, TEST_DESTINATION_DOMAIN, message.id()).await);
+}